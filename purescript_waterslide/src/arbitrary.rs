@@ -0,0 +1,375 @@
+//! Rendering of `purescript-quickcheck` `Arbitrary` instances.
+
+use codecs::{constraints, instance_head, is_constructor};
+use dates::is_naive_date_time;
+use purs_constructor::*;
+use purs_type::*;
+
+/// The imports needed by the generated instances, in the format of `PursModule`'s imports.
+pub const ARBITRARY_IMPORTS: &[(&str, &[&str])] = &[
+    ("Prelude", &[]),
+    ("Data.Array.NonEmpty", &["cons'"]),
+    ("Test.QuickCheck.Arbitrary", &["class Arbitrary", "arbitrary"]),
+    ("Test.QuickCheck.Gen", &["oneOf", "resize", "sized"]),
+];
+
+const MAP_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Map as Map", &[]),
+    ("Data.Tuple", &["Tuple(..)"]),
+    ("Test.QuickCheck.Gen", &["arrayOf"]),
+];
+const OBJECT_IMPORTS: &[(&str, &[&str])] = &[
+    ("Foreign.Object as Object", &[]),
+    ("Data.Tuple", &["Tuple(..)"]),
+    ("Test.QuickCheck.Gen", &["arrayOf"]),
+];
+const SET_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Set as Set", &[]),
+    ("Test.QuickCheck.Gen", &["arrayOf"]),
+];
+const BIG_INT_IMPORTS: &[(&str, &[&str])] = &[("JS.BigInt as BigInt", &[])];
+const DECIMAL_IMPORTS: &[(&str, &[&str])] = &[("Data.Decimal as Decimal", &[])];
+const JSON_IMPORTS: &[(&str, &[&str])] = &[("Data.Argonaut.Gen", &["genJson"])];
+const DATE_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Date as Date", &[]),
+    ("Data.Enum", &["toEnumWithDefaults"]),
+    ("Data.Enum.Gen", &["genBoundedEnum"]),
+    ("Test.QuickCheck.Gen", &["chooseInt"]),
+];
+const TIME_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Time as Time", &[]),
+    ("Data.Enum.Gen", &["genBoundedEnum"]),
+];
+const DATE_TIME_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Date as Date", &[]),
+    ("Data.DateTime as DateTime", &[]),
+    ("Data.Enum", &["toEnumWithDefaults"]),
+    ("Data.Enum.Gen", &["genBoundedEnum"]),
+    ("Data.Time as Time", &[]),
+    ("Test.QuickCheck.Gen", &["chooseInt"]),
+];
+const BYTES_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.ArrayBuffer.Typed as Typed", &[]),
+    ("Data.UInt as UInt", &[]),
+    ("Effect.Unsafe", &["unsafePerformEffect"]),
+    ("Test.QuickCheck.Gen", &["arrayOf", "chooseInt"]),
+];
+const BASE64_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.ArrayBuffer.Typed as Typed", &[]),
+    ("Data.Binary.Base64 as Base64", &[]),
+    ("Data.UInt as UInt", &[]),
+    ("Effect.Unsafe", &["unsafePerformEffect"]),
+    ("Test.QuickCheck.Gen", &["arrayOf", "chooseInt"]),
+];
+const FOREIGN_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Gen", &["genJson"]),
+    ("Foreign", &["unsafeToForeign"]),
+];
+const UNINHABITED_IMPORTS: &[(&str, &[&str])] = &[("Partial.Unsafe", &["unsafeCrashWith"])];
+/// The imports needed by the instances of opaque types declared in the module.
+pub const OPAQUE_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Gen", &["genJson"]),
+    ("Unsafe.Coerce", &["unsafeCoerce"]),
+];
+const ARRAY_IMPORTS: &[(&str, &[&str])] = &[("Test.QuickCheck.Gen", &["arrayOf"])];
+const MAYBE_IMPORTS: &[(&str, &[&str])] = &[("Data.Maybe", &["Maybe(..)"])];
+
+/// Types without an `Arbitrary` instance.
+fn lacks_instance(type_: &PursConstructor) -> bool {
+    is_constructor(type_, Some("JS.BigInt"), "BigInt")
+        || is_constructor(type_, Some("Data.Argonaut.Core"), "Json")
+        || is_constructor(type_, Some("Foreign"), "Foreign")
+        || is_constructor(type_, Some("Data.ArrayBuffer.Types"), "Uint8Array")
+        || is_constructor(type_, Some("Data.Void"), "Void")
+        || type_.marker() == Some(&Marker::Base64)
+        || is_constructor(type_, Some("Data.Decimal"), "Decimal")
+        || is_constructor(type_, Some("Data.Map"), "Map")
+        || is_constructor(type_, Some("Data.Set"), "Set")
+        || is_constructor(type_, Some("Foreign.Object"), "Object")
+        || is_constructor(type_, Some("Data.Date"), "Date")
+        || is_constructor(type_, Some("Data.Time"), "Time")
+        || is_constructor(type_, Some("Data.DateTime"), "DateTime")
+        || is_naive_date_time(type_)
+}
+
+/// Dates with four-digit years, well within chrono's range.
+const DATE_GENERATOR: &str = "(Date.canonicalDate <$> (toEnumWithDefaults bottom top <$> chooseInt 1 9999) <*> genBoundedEnum <*> genBoundedEnum)";
+const TIME_GENERATOR: &str =
+    "(Time.Time <$> genBoundedEnum <*> genBoundedEnum <*> genBoundedEnum <*> genBoundedEnum)";
+/// Arrays of bytes, which are only created in `Effect`.
+const BYTES_GENERATOR: &str =
+    "(unsafePerformEffect <<< Typed.fromArray <<< map UInt.fromInt <$> arrayOf (chooseInt 0 255))";
+
+fn is_void(type_: &PursConstructor) -> bool {
+    is_constructor(type_, Some("Data.Void"), "Void")
+}
+
+fn is_container(type_: &PursConstructor) -> bool {
+    is_constructor(type_, None, "Array") || is_constructor(type_, Some("Data.Maybe"), "Maybe")
+}
+
+/// Whether the type has no `Arbitrary` instance, and a generator has to be built for it.
+fn needs_generator(type_: &PursConstructor) -> bool {
+    lacks_instance(type_) || (is_container(type_) && type_.parameters().iter().any(needs_generator))
+}
+
+/// The imports needed by the generators built for the types in the module, in the format of
+/// `PursModule`'s imports.
+pub fn generator_imports(types: &[PursType]) -> Vec<(&'static str, &'static [&'static str])> {
+    fn collect(type_: &PursConstructor, imports: &mut Vec<(&'static str, &'static [&'static str])>) {
+        if type_.parameters().iter().any(is_void) {
+            if type_.name == "Maybe" {
+                imports.extend_from_slice(MAYBE_IMPORTS);
+            }
+            return;
+        }
+        if needs_generator(type_) {
+            imports.extend_from_slice(match type_.name.as_str() {
+                "Map" => MAP_IMPORTS,
+                "Object" => OBJECT_IMPORTS,
+                "Set" => SET_IMPORTS,
+                "BigInt" => BIG_INT_IMPORTS,
+                "Json" => JSON_IMPORTS,
+                "Foreign" => FOREIGN_IMPORTS,
+                "Uint8Array" => BYTES_IMPORTS,
+                "String" => BASE64_IMPORTS,
+                "Void" => UNINHABITED_IMPORTS,
+                "Decimal" => DECIMAL_IMPORTS,
+                "Date" => DATE_IMPORTS,
+                "Time" => TIME_IMPORTS,
+                "DateTime" | "NaiveDateTime" => DATE_TIME_IMPORTS,
+                "Maybe" => MAYBE_IMPORTS,
+                _ => ARRAY_IMPORTS,
+            });
+        }
+        for param in type_.parameters() {
+            collect(param, imports);
+        }
+    }
+
+    let mut imports = Vec::new();
+    for type_ in types {
+        if is_uninhabited(type_) {
+            imports.extend_from_slice(UNINHABITED_IMPORTS);
+        }
+        for field_type in type_.field_types() {
+            collect(field_type, &mut imports);
+        }
+    }
+    imports
+}
+
+/// The generator for a value of the given type.
+fn generator(type_: &PursConstructor) -> String {
+    if !needs_generator(type_) {
+        return "arbitrary".to_string();
+    }
+
+    // Containers of values that don't exist can only be empty.
+    if type_.parameters().iter().any(is_void) {
+        return if type_.name == "Maybe" {
+            "(pure Nothing)".to_string()
+        } else {
+            "(pure mempty)".to_string()
+        };
+    }
+
+    let params: Vec<String> = type_.parameters().iter().map(generator).collect();
+    match type_.name.as_str() {
+        "Map" => format!(
+            "(Map.fromFoldable <$> arrayOf (Tuple <$> {} <*> {}))",
+            params[0], params[1]
+        ),
+        "Object" => format!(
+            "(Object.fromFoldable <$> arrayOf (Tuple <$> arbitrary <*> {}))",
+            params[0]
+        ),
+        "Set" => format!("(Set.fromFoldable <$> arrayOf {})", params[0]),
+        "BigInt" if type_.marker() == Some(&Marker::BigUint) => {
+            "((\\n -> if n < zero then negate n else n) <<< BigInt.fromInt <$> arbitrary)".to_string()
+        }
+        "BigInt" => "(BigInt.fromInt <$> arbitrary)".to_string(),
+        "Json" => "genJson".to_string(),
+        "Foreign" => "(unsafeToForeign <$> genJson)".to_string(),
+        "Uint8Array" => BYTES_GENERATOR.to_string(),
+        "String" => format!("(Base64.encode <$> {})", BYTES_GENERATOR),
+        // Only run when a value is needed, since there are none.
+        "Void" => "(sized \\_ -> unsafeCrashWith \"Void has no values\")".to_string(),
+        "Decimal" => "(Decimal.fromNumber <$> arbitrary)".to_string(),
+        "Date" => DATE_GENERATOR.to_string(),
+        "Time" => TIME_GENERATOR.to_string(),
+        "DateTime" | "NaiveDateTime" => format!(
+            "(DateTime.DateTime <$> {} <*> {})",
+            DATE_GENERATOR, TIME_GENERATOR
+        ),
+        "Maybe" => format!("(oneOf (cons' (pure Nothing) [Just <$> {}]))", params[0]),
+        _ => format!("(arrayOf {})", params[0]),
+    }
+}
+
+/// The generator for a constructor applied to arbitrary arguments.
+fn apply_constructor(constructor: &PursConstructor) -> String {
+    let mut arguments = constructor.parameters().iter().map(generator);
+    match arguments.next() {
+        None => format!("pure {}", constructor.name),
+        Some(first) => {
+            let mut out = format!("{} <$> {}", constructor.name, first);
+            for argument in arguments {
+                out.push_str(&format!(" <*> {}", argument));
+            }
+            out
+        }
+    }
+}
+
+fn one_of(constructors: &[&PursConstructor]) -> String {
+    let generators: Vec<String> = constructors
+        .iter()
+        .map(|c| format!("({})", apply_constructor(c)))
+        .collect();
+    format!("oneOf (cons' {} [{}])", generators[0], generators[1..].join(", "))
+}
+
+/// Whether the type mentions one of the named types of the module.
+fn mentions(type_: &PursConstructor, names: &[&str]) -> bool {
+    (type_.module.is_none() && !type_.is_variable() && names.contains(&type_.name.as_str()))
+        || type_.parameters().iter().any(|p| mentions(p, names))
+}
+
+/// The types of the module the type's fields mention.
+fn references<'a>(type_: &PursType, types: &'a [PursType]) -> Vec<&'a PursType> {
+    let field_types = type_.field_types();
+    types
+        .iter()
+        .filter(|other| {
+            let name = other.constructor().name.as_str();
+            field_types.iter().any(|field_type| mentions(field_type, &[name]))
+        })
+        .collect()
+}
+
+/// The names of the types of the module the type mentions, directly or through other types.
+fn reachable<'a>(type_: &PursType, types: &'a [PursType]) -> Vec<&'a str> {
+    let mut names: Vec<&str> = Vec::new();
+    let mut pending = references(type_, types);
+    while let Some(other) = pending.pop() {
+        let name = other.constructor().name.as_str();
+        if !names.contains(&name) {
+            names.push(name);
+            pending.extend(references(other, types));
+        }
+    }
+    names
+}
+
+/// The names of the types of the module that are recursive with the type: the types it mentions,
+/// directly or through other types, and that mention it. It is among them if it is recursive.
+fn recursive_names<'a>(type_: &PursType, types: &'a [PursType]) -> Vec<&'a str> {
+    let name = type_.constructor().name.as_str();
+    reachable(type_, types)
+        .into_iter()
+        .filter(|other| {
+            types
+                .iter()
+                .filter(|t| t.constructor().name == *other)
+                .any(|t| reachable(t, types).contains(&name))
+        })
+        .collect()
+}
+
+/// Whether the type is an enum without constructors, which has no values to generate.
+fn is_uninhabited(type_: &PursType) -> bool {
+    match *type_ {
+        PursType::Enum(_, ref constructors, _) => constructors.is_empty(),
+        _ => false,
+    }
+}
+
+/// Wraps the generator of a recursive type so that the types it recurses through are generated
+/// with a smaller size, down to 0, where collections are empty.
+fn shrinking(generator: &str) -> String {
+    format!("sized \\size -> resize (max 0 (size - 1)) ({})", generator)
+}
+
+/// The `Arbitrary` instance for the type, given the other types of the module. Types that are
+/// recursive, directly or through other types, shrink the size of the values they contain, and
+/// recursive enums only pick their non-recursive constructors once the size is exhausted, so
+/// generation terminates. Enums without constructors crash, since they have no values.
+pub fn arbitrary_instance(type_: &PursType, types: &[PursType]) -> String {
+    let name = type_.constructor();
+    let recursive = recursive_names(type_, types);
+    let mut out = format!(
+        "instance arbitrary{} :: {}Arbitrary {} where\n",
+        name.name,
+        constraints("Arbitrary", name),
+        instance_head(name)
+    );
+
+    match *type_ {
+        PursType::Struct(_, ref fields, _) if fields.is_empty() => {
+            out.push_str(&format!("  arbitrary = pure ({} {{}})\n", name.name));
+        }
+        PursType::Struct(_, ref fields, _) => {
+            if recursive.is_empty() {
+                out.push_str("  arbitrary = do\n");
+            } else {
+                out.push_str("  arbitrary = sized \\size -> resize (max 0 (size - 1)) $ do\n");
+            }
+            // Fields are bound to fresh names, since labels can be keywords such as `data`.
+            let mut record_fields = Vec::new();
+            for (idx, field) in fields.iter().enumerate() {
+                out.push_str(&format!("    v{} <- {}\n", idx, generator(&field.type_)));
+                record_fields.push(format!(
+                    "{}: v{}",
+                    field.label(type_.attributes().label_case),
+                    idx
+                ));
+            }
+            out.push_str(&format!(
+                "    pure ({} {{ {} }})\n",
+                name.name,
+                record_fields.join(", ")
+            ));
+        }
+        PursType::TupleStruct(_, ref fields, _) => {
+            let constructor = PursConstructor::new(&name.name, None, fields.clone());
+            let generator = apply_constructor(&constructor);
+            if recursive.is_empty() {
+                out.push_str(&format!("  arbitrary = {}\n", generator));
+            } else {
+                out.push_str(&format!("  arbitrary = {}\n", shrinking(&generator)));
+            }
+        }
+        PursType::Enum(_, _, _) if is_uninhabited(type_) => {
+            out.push_str(&format!(
+                "  arbitrary = unsafeCrashWith \"{} has no values\"\n",
+                name.name
+            ));
+        }
+        PursType::Enum(_, ref constructors, _) => {
+            let all: Vec<&PursConstructor> = constructors.iter().map(|c| &c.constructor).collect();
+            let base: Vec<&PursConstructor> = all
+                .iter()
+                .cloned()
+                .filter(|c| !c.parameters().iter().any(|p| mentions(p, &recursive)))
+                .collect();
+
+            if base.len() == all.len() {
+                out.push_str(&format!("  arbitrary = {}\n", one_of(&all)));
+            } else if base.is_empty() {
+                out.push_str(&format!("  arbitrary = {}\n", shrinking(&one_of(&all))));
+            } else {
+                out.push_str(&format!(
+                    "  arbitrary = sized \\size ->\n    if size <= 0\n      then {}\n      else resize (size - 1) ({})\n",
+                    one_of(&base),
+                    one_of(&all)
+                ));
+            }
+        }
+        // Like their codecs, values of foreign types are arbitrary JSON.
+        PursType::Opaque(_, _) => out.push_str("  arbitrary = unsafeCoerce <$> genJson\n"),
+        PursType::Synonym(_, _, _) => unreachable!("Type synonyms cannot have instances"),
+    }
+
+    out
+}
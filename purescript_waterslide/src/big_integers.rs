@@ -0,0 +1,54 @@
+//! Arbitrary-precision integers, e.g. `num_bigint::BigUint` and `BigInt`.
+
+use purs_constructor::*;
+
+/// How arbitrary-precision integers serialized as their digits, such as num-bigint's, are
+/// represented. Set it for a module with `PursModule::with_big_integers`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BigIntegers {
+    /// Their serde representation, the default: `Array Number`s of little-endian base 2^32
+    /// digits, and for signed integers a `Tuple Int (Array Number)` with their sign, -1, 0 or 1.
+    #[default]
+    Digits,
+    /// `BigInt` from `purescript-js-bigints`. Generated codecs convert them from and to their
+    /// digits.
+    BigInt,
+}
+
+/// The constructor for unsigned integers serialized as their digits, displayed as an `Array
+/// Number` until a module applies its policy.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn big_unsigned() -> PursConstructor {
+    PursConstructor::new(
+        "Array",
+        None,
+        vec![<f64 as AsPursConstructor>::as_purs_constructor()],
+    ).with_marker(Marker::BigUint)
+}
+
+/// The constructor for signed integers serialized as their sign and digits, displayed as a `Tuple
+/// Int (Array Number)` until a module applies its policy.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn big_signed() -> PursConstructor {
+    PursConstructor::new(
+        "Tuple",
+        Some("Data.Tuple"),
+        vec![<i8 as AsPursConstructor>::as_purs_constructor(), big_unsigned()],
+    ).with_marker(Marker::BigInt)
+}
+
+/// Replaces the arbitrary-precision integers in the type with the policy's representation.
+pub fn apply_policy(type_: &mut PursConstructor, policy: BigIntegers) {
+    let big_integer = match type_.marker() {
+        Some(marker @ &Marker::BigUint) | Some(marker @ &Marker::BigInt) => Some(marker.clone()),
+        _ => None,
+    };
+    if let (Some(marker), BigIntegers::BigInt) = (big_integer, policy) {
+        *type_ = PursConstructor::new("BigInt", Some("JS.BigInt"), vec![]).with_marker(marker);
+        return;
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, policy);
+    }
+}
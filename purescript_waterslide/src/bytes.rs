@@ -0,0 +1,61 @@
+//! Byte buffers sent as base64 strings.
+
+use std::ops::{Deref, DerefMut};
+use purs_constructor::*;
+
+/// A byte buffer sent as a base64 `String`, e.g. with a base64 serde adapter. Its generated
+/// decoder checks that the string is valid base64 with the standard alphabet and padding.
+///
+/// Use it as a field type, or derive a field's type with `#[purs(bytes = "base64")]`. With the
+/// `base64_support` feature, it implements serde's `Serialize` and `Deserialize` as a base64 string.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Base64Bytes<T = Vec<u8>>(pub T);
+
+/// A byte buffer sent as a base64 string, and decoded to a `Uint8Array` from
+/// `purescript-arraybuffer-types` with `purescript-base64-codec`. The generated decoder fails on
+/// invalid base64.
+///
+/// Use it as a field type, or derive a field's type with `#[purs(bytes = "array_buffer")]`. With
+/// the `base64_support` feature, it implements serde's `Serialize` and `Deserialize` as a base64
+/// string.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ArrayBufferBytes<T = Vec<u8>>(pub T);
+
+impl<T> AsPursConstructor for Base64Bytes<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new("String", Some("PRIM"), vec![]).with_marker(Marker::Base64)
+    }
+}
+
+impl<T> AsPursConstructor for ArrayBufferBytes<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new("Uint8Array", Some("Data.ArrayBuffer.Types"), vec![])
+    }
+}
+
+macro_rules! bytes_wrapper_impls {
+    ($wrapper:ident) => {
+        impl<T> Deref for $wrapper<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $wrapper<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<T> for $wrapper<T> {
+            fn from(bytes: T) -> Self {
+                $wrapper(bytes)
+            }
+        }
+    }
+}
+
+bytes_wrapper_impls!(Base64Bytes);
+bytes_wrapper_impls!(ArrayBufferBytes);
@@ -0,0 +1,1131 @@
+//! Rendering of Argonaut `EncodeJson` and `DecodeJson` instances that mirror the JSON
+//! representation produced by `serde_json` with serde's default attributes.
+
+use purs_constructor::*;
+use purs_type::*;
+use dates::is_naive_date_time;
+use ordered_maps::is_ordered_map;
+use tuples::{element_names, nested_tuples, tuple_arity, Nesting};
+
+/// The imports needed by the generated instances, in the format of `PursModule`'s imports. An
+/// empty list stands for an open import.
+pub const CODEC_IMPORTS: &[(&str, &[&str])] = &[
+    ("Prelude", &[]),
+    (
+        "Data.Argonaut.Core",
+        &[
+            "fromArray",
+            "fromObject",
+            "fromString",
+            "isNull",
+            "jsonNull",
+            "toArray",
+            "toObject",
+            "toString",
+        ],
+    ),
+    (
+        "Data.Argonaut.Decode",
+        &[
+            "class DecodeJson",
+            "JsonDecodeError(..)",
+            "decodeJson",
+            "(.:)",
+            "(.:?)",
+        ],
+    ),
+    ("Data.Argonaut.Encode", &["class EncodeJson", "encodeJson"]),
+    ("Data.Either", &["Either(..)"]),
+    ("Data.Maybe", &["Maybe(..)"]),
+    ("Data.Tuple", &["Tuple(..)"]),
+    ("Foreign.Object", &["fromFoldable", "singleton", "toUnfoldable"]),
+];
+
+/// The imports needed by the instances of opaque types declared in the module.
+pub const OPAQUE_IMPORTS: &[(&str, &[&str])] = &[("Unsafe.Coerce", &["unsafeCoerce"])];
+
+/// The imports needed to build the codecs of optional values from the codecs of their elements.
+const MAYBE_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// The imports needed to build the codecs of the other containers from the codecs of their
+/// elements.
+const CONTAINER_IMPORTS: &[(&str, &[&str])] = &[("Data.Traversable", &["traverse"])];
+
+/// The name of the type with its first letter lowercased, used as a prefix for functions.
+fn function_prefix(type_: &PursConstructor) -> String {
+    let mut chars = type_.name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// The type as it appears in an instance head, e.g. `(Paginated t meta)`.
+pub fn instance_head(type_: &PursConstructor) -> String {
+    if type_.parameters().is_empty() {
+        type_.name.clone()
+    } else {
+        let params: Vec<&str> = type_.parameters().iter().map(|p| p.name.as_str()).collect();
+        format!("({} {})", type_.name, params.join(" "))
+    }
+}
+
+/// The class constraints on the type parameters, e.g. `(EncodeJson t, EncodeJson meta) => `.
+pub fn constraints(class: &str, type_: &PursConstructor) -> String {
+    let constraints: Vec<String> = type_
+        .parameters()
+        .iter()
+        .map(|p| format!("{} {}", class, p.name))
+        .collect();
+    match constraints.len() {
+        0 => String::new(),
+        1 => format!("{} => ", constraints[0]),
+        _ => format!("({}) => ", constraints.join(", ")),
+    }
+}
+
+/// The number literal for a discriminant, parenthesized when negative so it can be used as a
+/// pattern.
+fn int_literal(value: i64) -> String {
+    if value < 0 {
+        format!("({})", value)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Whether the type is an enum encoded as its discriminants.
+pub fn is_int_enum(type_: &PursType) -> bool {
+    match *type_ {
+        PursType::Enum(_, ref constructors, _) => {
+            !constructors.is_empty() && constructors.iter().all(|c| c.discriminant.is_some())
+        }
+        _ => false,
+    }
+}
+
+/// Functions generated once per module for types whose Argonaut instances don't match serde_json.
+pub struct Helper {
+    /// The name of the encoding and decoding functions, following `encode` and `decode`.
+    name: String,
+    /// The Purescript declarations.
+    pub functions: String,
+    /// The imports they need, in the format of `PursModule`'s imports.
+    pub imports: &'static [(&'static str, &'static [&'static str])],
+}
+
+/// Converts map keys from and to JSON object keys, as serde_json does: keys are encoded to JSON
+/// and stringified, and decoded either as JSON strings or by parsing them.
+const MAP_KEY_FUNCTIONS: &str = "encodeMapKey :: Json -> String
+encodeMapKey json = fromMaybe (stringify json) (toString json)
+
+decodeMapKey :: forall k. (Json -> Either JsonDecodeError k) -> String -> Either JsonDecodeError k
+decodeMapKey decodeKey key = case decodeKey (fromString key) of
+  Right k -> Right k
+  Left err -> case jsonParser key of
+    Right json -> decodeKey json
+    Left _ -> Left err
+";
+
+const MAP_KEY_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json", "stringify"]),
+    ("Data.Argonaut.Parser", &["jsonParser"]),
+    ("Data.Maybe", &["fromMaybe"]),
+];
+
+/// Converts `Map`s from and to JSON objects.
+const MAP_FUNCTIONS: &str = "encodeMap :: forall k v. (k -> Json) -> (v -> Json) -> Map k v -> Json
+encodeMap encodeKey encodeValue m = fromObject (fromFoldable (map encodeEntry entries))
+  where
+  entries :: Array (Tuple k v)
+  entries = Map.toUnfoldable m
+  encodeEntry (Tuple k v) = Tuple (encodeMapKey (encodeKey k)) (encodeValue v)
+
+decodeMap :: forall k v. Ord k => (Json -> Either JsonDecodeError k) -> (Json -> Either JsonDecodeError v) -> Json -> Either JsonDecodeError (Map k v)
+decodeMap decodeKey decodeValue json = do
+  object <- decodeJson json
+  let
+    entries :: Array (Tuple String Json)
+    entries = toUnfoldable object
+    decodeEntry (Tuple k v) = Tuple <$> decodeMapKey decodeKey k <*> decodeValue v
+  Map.fromFoldable <$> traverse decodeEntry entries
+";
+
+const MAP_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Map", &["Map"]),
+    ("Data.Map as Map", &[]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Converts `OrderedMap`s from and to JSON objects, keeping the order of their keys. JavaScript
+/// objects list integer-like keys first, in ascending order, whatever the order in the JSON.
+const ORDERED_MAP_FUNCTIONS: &str = "encodeOrderedMap :: forall k v. (k -> Json) -> (v -> Json) -> OrderedMap k v -> Json
+encodeOrderedMap encodeKey encodeValue entries = fromObject (fromFoldable (map encodeEntry entries))
+  where
+  encodeEntry (Tuple k v) = Tuple (encodeMapKey (encodeKey k)) (encodeValue v)
+
+decodeOrderedMap :: forall k v. (Json -> Either JsonDecodeError k) -> (Json -> Either JsonDecodeError v) -> Json -> Either JsonDecodeError (OrderedMap k v)
+decodeOrderedMap decodeKey decodeValue json = do
+  object <- decodeJson json
+  let
+    entries :: Array (Tuple String Json)
+    entries = toUnfoldable object
+    decodeEntry (Tuple k v) = Tuple <$> decodeMapKey decodeKey k <*> decodeValue v
+  traverse decodeEntry entries
+";
+
+const ORDERED_MAP_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Converts `Either`s from and to serde's representation of `Result`s, `{"Ok": ...}` or
+/// `{"Err": ...}`.
+const RESULT_FUNCTIONS: &str = "encodeResult :: forall e t. (e -> Json) -> (t -> Json) -> Either e t -> Json
+encodeResult encodeErr encodeOk = either
+  (\\err -> fromObject (singleton \"Err\" (encodeErr err)))
+  (\\ok -> fromObject (singleton \"Ok\" (encodeOk ok)))
+
+decodeResult :: forall e t. (Json -> Either JsonDecodeError e) -> (Json -> Either JsonDecodeError t) -> Json -> Either JsonDecodeError (Either e t)
+decodeResult decodeErr decodeOk json = case map toUnfoldable (toObject json) of
+  Just [Tuple \"Ok\" value] -> Right <$> decodeOk value
+  Just [Tuple \"Err\" value] -> Left <$> decodeErr value
+  _ -> Left (UnexpectedValue json)
+";
+
+const RESULT_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Either", &["either"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Converts `Either`s from and to the representation of the either crate's `Either`s,
+/// `{"Left": ...}` or `{"Right": ...}`.
+const EITHER_FUNCTIONS: &str = "encodeEither :: forall l r. (l -> Json) -> (r -> Json) -> Either l r -> Json
+encodeEither encodeLeft encodeRight = either
+  (\\left -> fromObject (singleton \"Left\" (encodeLeft left)))
+  (\\right -> fromObject (singleton \"Right\" (encodeRight right)))
+
+decodeEither :: forall l r. (Json -> Either JsonDecodeError l) -> (Json -> Either JsonDecodeError r) -> Json -> Either JsonDecodeError (Either l r)
+decodeEither decodeLeft decodeRight json = case map toUnfoldable (toObject json) of
+  Just [Tuple \"Left\" value] -> Left <$> decodeLeft value
+  Just [Tuple \"Right\" value] -> Right <$> decodeRight value
+  _ -> Left (UnexpectedValue json)
+";
+
+/// Converts `BigInt`s from and to JSON strings, which keep their precision, also accepting numbers.
+const BIG_INT_FUNCTIONS: &str = "encodeBigInt :: BigInt -> Json
+encodeBigInt = fromString <<< BigInt.toString
+
+decodeBigInt :: Json -> Either JsonDecodeError BigInt
+decodeBigInt json = note (UnexpectedValue json) $ case toString json of
+  Just string -> BigInt.fromString string
+  Nothing -> BigInt.fromNumber =<< toNumber json
+";
+
+const BIG_INT_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json", "toNumber"]),
+    ("Data.Either", &["note"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+    ("JS.BigInt", &["BigInt"]),
+    ("JS.BigInt as BigInt", &[]),
+];
+
+/// Converts `BigInt`s from and to the little-endian base 2^32 digits num-bigint serializes
+/// unsigned integers as.
+const BIG_UINT_DIGITS_FUNCTIONS: &str = "encodeBigUintDigits :: BigInt -> Json
+encodeBigUintDigits = fromArray <<< map (fromNumber <<< BigInt.toNumber) <<< unfoldr digit
+  where
+  digit n = if n <= zero then Nothing else Just (Tuple (n `mod` bigDigitBase) (n `div` bigDigitBase))
+
+decodeBigUintDigits :: Json -> Either JsonDecodeError BigInt
+decodeBigUintDigits json = do
+  digits <- decodeJson json
+  note (UnexpectedValue json) $ foldr addDigit (Just zero) digits
+  where
+  addDigit digit n = (\\d rest -> d + rest * bigDigitBase) <$> BigInt.fromNumber digit <*> n
+
+bigDigitBase :: BigInt
+bigDigitBase = BigInt.fromInt 65536 * BigInt.fromInt 65536
+";
+
+/// Converts `BigInt`s from and to the sign and digits num-bigint serializes signed integers as.
+const BIG_INT_DIGITS_FUNCTIONS: &str = "encodeBigIntDigits :: BigInt -> Json
+encodeBigIntDigits n = fromArray [encodeJson sign, encodeBigUintDigits (if n < zero then negate n else n)]
+  where
+  sign = if n < zero then -1 else if n == zero then 0 else 1
+
+decodeBigIntDigits :: Json -> Either JsonDecodeError BigInt
+decodeBigIntDigits json = case toArray json of
+  Just [sign, digits] -> do
+    s <- decodeJson sign
+    n <- decodeBigUintDigits digits
+    pure (if s < 0 then negate n else n)
+  _ -> Left (UnexpectedValue json)
+";
+
+const BIG_INT_DIGITS_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json", "fromNumber"]),
+    ("Data.Either", &["note"]),
+    ("Data.Foldable", &["foldr"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+    ("Data.Tuple", &["Tuple(..)"]),
+    ("Data.Unfoldable", &["unfoldr"]),
+    ("JS.BigInt", &["BigInt"]),
+    ("JS.BigInt as BigInt", &[]),
+];
+
+/// Converts `Decimal`s from and to JSON strings, also accepting numbers.
+const DECIMAL_FUNCTIONS: &str = "encodeDecimal :: Decimal -> Json
+encodeDecimal = fromString <<< Decimal.toString
+
+decodeDecimal :: Json -> Either JsonDecodeError Decimal
+decodeDecimal json = note (UnexpectedValue json) $ case toString json of
+  Just string -> Decimal.fromString string
+  Nothing -> Decimal.fromNumber <$> toNumber json
+";
+
+const DECIMAL_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json", "toNumber"]),
+    ("Data.Decimal", &["Decimal"]),
+    ("Data.Decimal as Decimal", &[]),
+    ("Data.Either", &["note"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Validates base64 strings, which are otherwise plain `String`s.
+const BASE64_FUNCTIONS: &str = "encodeBase64 :: String -> Json
+encodeBase64 = fromString
+
+decodeBase64 :: Json -> Either JsonDecodeError String
+decodeBase64 json = do
+  string <- decodeJson json
+  if String.length string `mod` 4 == 0 && Regex.test base64Pattern string
+    then Right string
+    else Left (UnexpectedValue json)
+
+base64Pattern :: Regex
+base64Pattern = unsafeRegex \"^[A-Za-z0-9+/]*={0,2}$\" noFlags
+";
+
+const BASE64_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.String as String", &[]),
+    ("Data.String.Regex", &["Regex"]),
+    ("Data.String.Regex as Regex", &[]),
+    ("Data.String.Regex.Flags", &["noFlags"]),
+    ("Data.String.Regex.Unsafe", &["unsafeRegex"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Converts `Uint8Array`s from and to base64 strings.
+const UINT8_ARRAY_FUNCTIONS: &str = "encodeUint8Array :: Uint8Array -> Json
+encodeUint8Array = fromString <<< Base64.encode
+
+decodeUint8Array :: Json -> Either JsonDecodeError Uint8Array
+decodeUint8Array json = do
+  string <- decodeJson json
+  either (const (Left (UnexpectedValue json))) Right (Base64.decode string)
+";
+
+const UINT8_ARRAY_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.ArrayBuffer.Types", &["Uint8Array"]),
+    ("Data.Binary.Base64 as Base64", &[]),
+    ("Data.Either", &["either"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// Converts dates and times from and to the formats of chrono's serde implementations. The
+/// functions are shared by the helpers of `Date`, `Time`, `DateTime` and `NaiveDateTime`.
+const DATE_TIME_FUNCTIONS: &str = "encodeDate :: Date -> Json
+encodeDate = fromString <<< printDate
+
+decodeDate :: Json -> Either JsonDecodeError Date
+decodeDate = decodeDateString parseDate
+
+encodeTime :: Time -> Json
+encodeTime = fromString <<< printTime
+
+decodeTime :: Json -> Either JsonDecodeError Time
+decodeTime = decodeDateString parseTime
+
+encodeDateTime :: DateTime -> Json
+encodeDateTime (DateTime.DateTime d t) = fromString (printDate d <> \"T\" <> printTime t <> \"Z\")
+
+decodeDateTime :: Json -> Either JsonDecodeError DateTime
+decodeDateTime = decodeDateString parseDateTime
+
+encodeNaiveDateTime :: DateTime -> Json
+encodeNaiveDateTime (DateTime.DateTime d t) = fromString (printDate d <> \"T\" <> printTime t)
+
+decodeNaiveDateTime :: Json -> Either JsonDecodeError DateTime
+decodeNaiveDateTime = decodeDateString parseNaiveDateTime
+
+decodeDateString :: forall a. (String -> Maybe a) -> Json -> Either JsonDecodeError a
+decodeDateString parse json = note (UnexpectedValue json) <<< parse =<< decodeJson json
+
+printDate :: Date -> String
+printDate d = printYear (fromEnum (Date.year d)) <> \"-\" <> pad 2 (fromEnum (Date.month d)) <> \"-\" <> pad 2 (fromEnum (Date.day d))
+  where
+  printYear y
+    | y > 9999 = \"+\" <> show y
+    | y < 0 = \"-\" <> pad 4 (negate y)
+    | otherwise = pad 4 y
+
+printTime :: Time -> String
+printTime t = pad 2 (fromEnum (Time.hour t)) <> \":\" <> pad 2 (fromEnum (Time.minute t)) <> \":\" <> pad 2 (fromEnum (Time.second t)) <> fraction
+  where
+  fraction = \".\" <> pad 3 (fromEnum (Time.millisecond t))
+
+pad :: Int -> Int -> String
+pad width n = power \"0\" (width - String.length digits) <> digits
+  where
+  digits = show n
+
+dateSource :: String
+dateSource = \"([+-]?[0-9]{4,})-([0-9]{2})-([0-9]{2})\"
+
+timeSource :: String
+timeSource = \"([0-9]{2}):([0-9]{2}):([0-9]{2})(?:[.]([0-9]+))?\"
+
+matchGroups :: String -> String -> Maybe (Array String)
+matchGroups source string = map (fromMaybe \"\") <<< NonEmptyArray.tail <$> Regex.match (unsafeRegex (\"^\" <> source <> \"$\") noFlags) string
+
+parseDate :: String -> Maybe Date
+parseDate string = case matchGroups dateSource string of
+  Just [y, m, d] -> toDate y m d
+  _ -> Nothing
+
+parseTime :: String -> Maybe Time
+parseTime string = case matchGroups timeSource string of
+  Just [h, m, s, fraction] -> toTime h m s fraction
+  _ -> Nothing
+
+parseDateTime :: String -> Maybe DateTime
+parseDateTime string = case matchGroups (dateSource <> \"[Tt ]\" <> timeSource <> \"(?:[Zz]|([+-])([0-9]{2}):([0-9]{2}))\") string of
+  Just [y, mo, d, h, mi, s, fraction, sign, offsetHours, offsetMinutes] -> do
+    local <- DateTime.DateTime <$> toDate y mo d <*> toTime h mi s fraction
+    DateTime.adjust (toUtc sign offsetHours offsetMinutes) local
+  _ -> Nothing
+
+parseNaiveDateTime :: String -> Maybe DateTime
+parseNaiveDateTime string = case matchGroups (dateSource <> \"[Tt ]\" <> timeSource) string of
+  Just [y, mo, d, h, mi, s, fraction] -> DateTime.DateTime <$> toDate y mo d <*> toTime h mi s fraction
+  _ -> Nothing
+
+toDate :: String -> String -> String -> Maybe Date
+toDate y m d = do
+  year <- toEnum =<< Int.fromString y
+  month <- toEnum =<< Int.fromString m
+  day <- toEnum =<< Int.fromString d
+  Date.exactDate year month day
+
+toTime :: String -> String -> String -> String -> Maybe Time
+toTime h m s fraction = Time.Time
+  <$> (toEnum =<< Int.fromString h)
+  <*> (toEnum =<< Int.fromString m)
+  <*> (toEnum =<< Int.fromString s)
+  <*> (toEnum =<< Int.fromString (String.take 3 (fraction <> \"000\")))
+
+-- | The duration to add to a local time with the given offset to get the time in UTC.
+toUtc :: String -> String -> String -> Minutes
+toUtc sign hours minutes = Minutes (if sign == \"-\" then offset else negate offset)
+  where
+  offset = Int.toNumber (fromMaybe 0 (Int.fromString hours) * 60 + fromMaybe 0 (Int.fromString minutes))
+";
+
+const DATE_TIME_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Array.NonEmpty as NonEmptyArray", &[]),
+    ("Data.Date", &["Date"]),
+    ("Data.Date as Date", &[]),
+    ("Data.DateTime", &["DateTime"]),
+    ("Data.DateTime as DateTime", &[]),
+    ("Data.Either", &["note"]),
+    ("Data.Enum", &["fromEnum", "toEnum"]),
+    ("Data.Int as Int", &[]),
+    ("Data.Maybe", &["fromMaybe", "maybe"]),
+    ("Data.Monoid", &["power"]),
+    ("Data.String as String", &[]),
+    ("Data.String.Regex as Regex", &[]),
+    ("Data.String.Regex.Flags", &["noFlags"]),
+    ("Data.String.Regex.Unsafe", &["unsafeRegex"]),
+    ("Data.Time", &["Time"]),
+    ("Data.Time as Time", &[]),
+    ("Data.Time.Duration", &["Minutes(..)"]),
+    ("Data.Traversable", &["traverse"]),
+];
+
+/// The imports of the functions converting tuples from and to JSON arrays, as serde_json does.
+const TUPLE_IMPORTS: &[(&str, &[&str])] = &[
+    ("Data.Argonaut.Core", &["Json"]),
+    ("Data.Maybe", &["maybe"]),
+    ("Data.Traversable", &["traverse"]),
+    ("Data.Tuple", &["Tuple(..)"]),
+];
+
+/// The functions converting tuples of the given synonym and arity.
+fn tuple_functions(name: &str, arity: usize, nesting: Nesting) -> String {
+    let elements = element_names(arity);
+    let encoders: Vec<String> = elements
+        .iter()
+        .map(|e| format!("encode{}", e.to_uppercase()))
+        .collect();
+    let decoders: Vec<String> = elements
+        .iter()
+        .map(|e| format!("decode{}", e.to_uppercase()))
+        .collect();
+    let type_ = format!("({} {})", name, elements.join(" "));
+
+    let mut out = format!(
+        "encode{name} :: forall {elements}. {signatures}{type_} -> Json\nencode{name} {encoders} ({pattern}) = fromArray [{encoded}]\n",
+        name = name,
+        elements = elements.join(" "),
+        signatures = elements
+            .iter()
+            .map(|e| format!("({} -> Json) -> ", e))
+            .collect::<String>(),
+        type_ = type_,
+        encoders = encoders.join(" "),
+        pattern = nested_tuples(&elements, nesting).replace("Unit", "_"),
+        encoded = encoders
+            .iter()
+            .zip(elements.iter())
+            .map(|(encoder, e)| format!("{} {}", encoder, e))
+            .collect::<Vec<String>>()
+            .join(", "),
+    );
+
+    let decoded: Vec<String> = decoders
+        .iter()
+        .zip(elements.iter())
+        .map(|(decoder, e)| format!("{} {}", decoder, e))
+        .collect();
+    let (last, init) = match nesting {
+        Nesting::UnitTerminated => ("pure unit".to_string(), &decoded[..]),
+        Nesting::Direct => (decoded[arity - 1].clone(), &decoded[..arity - 1]),
+    };
+    let applied = init.iter().rev().fold(last, |nested, decoded| {
+        if nested.contains("<*>") {
+            format!("Tuple <$> {} <*> ({})", decoded, nested)
+        } else {
+            format!("Tuple <$> {} <*> {}", decoded, nested)
+        }
+    });
+    out.push_str(&format!(
+        "\ndecode{name} :: forall {elements}. {signatures}Json -> Either JsonDecodeError {type_}\ndecode{name} {decoders} json = case toArray json of\n  Just [{elements_list}] -> {applied}\n  _ -> Left (UnexpectedValue json)\n",
+        name = name,
+        elements = elements.join(" "),
+        signatures = elements
+            .iter()
+            .map(|e| format!("(Json -> Either JsonDecodeError {}) -> ", e))
+            .collect::<String>(),
+        type_ = type_,
+        decoders = decoders.join(" "),
+        elements_list = elements.join(", "),
+        applied = applied,
+    ));
+    out
+}
+
+/// Whether the type is the named constructor from the given module.
+pub fn is_constructor(type_: &PursConstructor, module: Option<&str>, name: &str) -> bool {
+    type_.name == name && type_.module.as_deref() == module
+}
+
+fn is_map(type_: &PursConstructor) -> bool {
+    is_constructor(type_, Some("Data.Map"), "Map")
+}
+
+fn is_result(type_: &PursConstructor) -> bool {
+    is_constructor(type_, Some("Data.Either"), "Either") && type_.marker() != Some(&Marker::Either)
+}
+
+/// The name of a `purescript-datetime` type, or of the `NaiveDateTime` synonym.
+fn date_time_name(type_: &PursConstructor) -> Option<&'static str> {
+    if is_constructor(type_, Some("Data.Date"), "Date") {
+        Some("Date")
+    } else if is_constructor(type_, Some("Data.Time"), "Time") {
+        Some("Time")
+    } else if is_constructor(type_, Some("Data.DateTime"), "DateTime") {
+        Some("DateTime")
+    } else if is_naive_date_time(type_) {
+        Some("NaiveDateTime")
+    } else {
+        None
+    }
+}
+
+/// The helper for types whose codecs are implemented by generated functions. Pairs only need one
+/// when their elements do.
+fn helper(type_: &PursConstructor) -> Option<Helper> {
+    if is_map(type_) {
+        return Some(Helper {
+            name: "Map".to_string(),
+            functions: MAP_FUNCTIONS.to_string(),
+            imports: MAP_IMPORTS,
+        });
+    }
+    if is_ordered_map(type_) {
+        return Some(Helper {
+            name: "OrderedMap".to_string(),
+            functions: ORDERED_MAP_FUNCTIONS.to_string(),
+            imports: ORDERED_MAP_IMPORTS,
+        });
+    }
+    if type_.marker() == Some(&Marker::Either) {
+        return Some(Helper {
+            name: "Either".to_string(),
+            functions: EITHER_FUNCTIONS.to_string(),
+            imports: RESULT_IMPORTS,
+        });
+    }
+    if type_.marker() == Some(&Marker::Base64) {
+        return Some(Helper {
+            name: "Base64".to_string(),
+            functions: BASE64_FUNCTIONS.to_string(),
+            imports: BASE64_IMPORTS,
+        });
+    }
+    if is_constructor(type_, Some("Data.ArrayBuffer.Types"), "Uint8Array") {
+        return Some(Helper {
+            name: "Uint8Array".to_string(),
+            functions: UINT8_ARRAY_FUNCTIONS.to_string(),
+            imports: UINT8_ARRAY_IMPORTS,
+        });
+    }
+    if is_constructor(type_, Some("JS.BigInt"), "BigInt") {
+        // num-bigint's integers are serialized as their digits, other wide integers as strings.
+        match type_.marker() {
+            Some(&Marker::BigUint) => {
+                return Some(Helper {
+                    name: "BigUintDigits".to_string(),
+                    functions: BIG_UINT_DIGITS_FUNCTIONS.to_string(),
+                    imports: BIG_INT_DIGITS_IMPORTS,
+                })
+            }
+            Some(&Marker::BigInt) => {
+                return Some(Helper {
+                    name: "BigIntDigits".to_string(),
+                    functions: BIG_INT_DIGITS_FUNCTIONS.to_string(),
+                    imports: BIG_INT_DIGITS_IMPORTS,
+                })
+            }
+            _ => {}
+        }
+        return Some(Helper {
+            name: "BigInt".to_string(),
+            functions: BIG_INT_FUNCTIONS.to_string(),
+            imports: BIG_INT_IMPORTS,
+        });
+    }
+    if is_constructor(type_, Some("Data.Decimal"), "Decimal") {
+        return Some(Helper {
+            name: "Decimal".to_string(),
+            functions: DECIMAL_FUNCTIONS.to_string(),
+            imports: DECIMAL_IMPORTS,
+        });
+    }
+    if let Some(name) = date_time_name(type_) {
+        return Some(Helper {
+            name: name.to_string(),
+            functions: DATE_TIME_FUNCTIONS.to_string(),
+            imports: DATE_TIME_IMPORTS,
+        });
+    }
+    if is_result(type_) {
+        return Some(Helper {
+            name: "Result".to_string(),
+            functions: RESULT_FUNCTIONS.to_string(),
+            imports: RESULT_IMPORTS,
+        });
+    }
+
+    match tuple_arity(type_) {
+        Some((2, _)) if !type_.parameters().iter().any(needs_codec) => None,
+        Some((arity, nesting)) => Some(Helper {
+            name: type_.name.clone(),
+            functions: tuple_functions(&type_.name, arity, nesting),
+            imports: TUPLE_IMPORTS,
+        }),
+        None => None,
+    }
+}
+
+/// The helpers whose functions the helper uses, which are added to the module with it.
+fn requirements(helper: &Helper) -> Vec<Helper> {
+    match helper.name.as_str() {
+        "Map" | "OrderedMap" => vec![Helper {
+            name: "MapKey".to_string(),
+            functions: MAP_KEY_FUNCTIONS.to_string(),
+            imports: MAP_KEY_IMPORTS,
+        }],
+        "BigIntDigits" => vec![Helper {
+            name: "BigUintDigits".to_string(),
+            functions: BIG_UINT_DIGITS_FUNCTIONS.to_string(),
+            imports: BIG_INT_DIGITS_IMPORTS,
+        }],
+        _ => Vec::new(),
+    }
+}
+
+/// Containers whose codecs can be built from the codecs of their elements.
+fn is_container(type_: &PursConstructor) -> bool {
+    is_constructor(type_, None, "Array") || is_maybe(type_)
+        || is_constructor(type_, Some("Foreign.Object"), "Object")
+        || is_constructor(type_, Some("Data.Array.NonEmpty"), "NonEmptyArray")
+}
+
+/// Whether the Argonaut instances for the type don't match serde_json's representation. Records
+/// need a codec when their labels aren't their JSON names, or their fields need one.
+fn needs_codec(type_: &PursConstructor) -> bool {
+    helper(type_).is_some()
+        || (is_container(type_) && type_.parameters().iter().any(needs_codec))
+        || (type_.is_record()
+            && type_
+                .json_record_fields()
+                .into_iter()
+                .any(|(label, json_name, field_type)| label != json_name || needs_codec(field_type)))
+}
+
+/// The helpers used by the codecs of the types in the module, each once.
+pub fn helpers(types: &[PursType]) -> Vec<Helper> {
+    fn collect(type_: &PursConstructor, helpers: &mut Vec<Helper>) {
+        // Helpers can share their functions, like the date and time ones.
+        if let Some(helper) = helper(type_) {
+            for helper in requirements(&helper).into_iter().chain(Some(helper)) {
+                if !helpers.iter().any(|h| h.functions == helper.functions) {
+                    helpers.push(helper);
+                }
+            }
+        }
+        for param in type_.parameters() {
+            collect(param, helpers);
+        }
+    }
+
+    // Type synonyms have no codecs: the codecs of the types using them inline their definitions.
+    let mut helpers = Vec::new();
+    for type_ in types.iter().filter(|type_| !type_.is_type_synonym()) {
+        for field_type in type_.field_types() {
+            collect(field_type, &mut helpers);
+        }
+    }
+    helpers
+}
+
+/// The imports needed by the codecs of containers built from the codecs of their elements, such
+/// as the `Maybe`s of inlined records, in the format of `PursModule`'s imports.
+pub fn combinator_imports(types: &[PursType]) -> Vec<(&'static str, &'static [&'static str])> {
+    fn collect(type_: &PursConstructor, imports: &mut Vec<(&'static str, &'static [&'static str])>) {
+        if helper(type_).is_none() && is_container(type_) && needs_codec(type_) {
+            imports.extend_from_slice(if is_maybe(type_) {
+                MAYBE_IMPORTS
+            } else {
+                CONTAINER_IMPORTS
+            });
+        }
+        for param in type_.parameters() {
+            collect(param, imports);
+        }
+    }
+
+    let mut imports = Vec::new();
+    for type_ in types.iter().filter(|type_| !type_.is_type_synonym()) {
+        for field_type in type_.field_types() {
+            collect(field_type, &mut imports);
+        }
+    }
+    imports
+}
+
+/// The expression encoding a value of the given type to `Json`.
+fn encoder(type_: &PursConstructor) -> String {
+    if !needs_codec(type_) {
+        return "encodeJson".to_string();
+    }
+
+    if type_.is_record() {
+        let fields: Vec<String> = type_
+            .json_record_fields()
+            .into_iter()
+            .map(|(label, json_name, field_type)| {
+                format!("Tuple \"{}\" ({} record.{})", json_name, encoder(field_type), label)
+            })
+            .collect();
+        return format!(
+            "(\\record -> fromObject (fromFoldable [{}]))",
+            fields.join(", ")
+        );
+    }
+
+    let params: Vec<String> = type_.parameters().iter().map(encoder).collect();
+    if let Some(helper) = helper(type_) {
+        if params.is_empty() {
+            format!("encode{}", helper.name)
+        } else {
+            format!("(encode{} {})", helper.name, params.join(" "))
+        }
+    } else if is_maybe(type_) {
+        format!("(maybe jsonNull {})", params[0])
+    } else {
+        format!("(encodeJson <<< map {})", params[0])
+    }
+}
+
+/// The expression decoding a value of the given type from `Json`, or `None` if `decodeJson` can
+/// be used.
+fn decoder(type_: &PursConstructor) -> Option<String> {
+    if !needs_codec(type_) {
+        return None;
+    }
+
+    if type_.is_record() {
+        let fields = type_.json_record_fields();
+        let labels: Vec<String> = fields
+            .iter()
+            .map(|&(label, _, _)| format!("{}: _", label))
+            .collect();
+        let decoded: Vec<String> = fields
+            .iter()
+            .map(|&(_, json_name, field_type)| format!("({})", decode_field(json_name, field_type)))
+            .collect();
+        return Some(format!(
+            "(\\json -> decodeJson json >>= \\object -> {{ {} }} <$> {})",
+            labels.join(", "),
+            decoded.join(" <*> ")
+        ));
+    }
+
+    let params: Vec<String> = type_
+        .parameters()
+        .iter()
+        .map(|p| decoder(p).unwrap_or_else(|| "decodeJson".to_string()))
+        .collect();
+    Some(if let Some(helper) = helper(type_) {
+        if params.is_empty() {
+            format!("decode{}", helper.name)
+        } else {
+            format!("(decode{} {})", helper.name, params.join(" "))
+        }
+    } else if is_maybe(type_) {
+        format!(
+            "(\\json -> if isNull json then Right Nothing else Just <$> {} json)",
+            params[0]
+        )
+    } else {
+        format!("(traverse {} <=< decodeJson)", params[0])
+    })
+}
+
+fn is_maybe(type_: &PursConstructor) -> bool {
+    is_constructor(type_, Some("Data.Maybe"), "Maybe")
+}
+
+fn decode_value(type_: &PursConstructor, value: &str) -> String {
+    format!(
+        "{} {}",
+        decoder(type_).unwrap_or_else(|| "decodeJson".to_string()),
+        value
+    )
+}
+
+fn decode_field(name: &str, type_: &PursConstructor) -> String {
+    match decoder(type_) {
+        Some(_) if is_maybe(type_) => format!(
+            "traverse {} =<< object .:? \"{}\"",
+            decoder(&type_.parameters()[0]).unwrap_or_default(),
+            name
+        ),
+        Some(decoder) => format!("{} =<< object .: \"{}\"", decoder, name),
+        None if is_maybe(type_) => format!("object .:? \"{}\"", name),
+        None => format!("object .: \"{}\"", name),
+    }
+}
+
+/// Encodes positional arguments as serde does: a single argument stands for itself, multiple
+/// arguments are a JSON array.
+fn encode_arguments(arguments: &[PursConstructor]) -> String {
+    let encoded: Vec<String> = arguments
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| format!("{} a{}", encoder(arg), idx))
+        .collect();
+    if encoded.len() == 1 {
+        format!("({})", encoded[0])
+    } else {
+        format!("(fromArray [{}])", encoded.join(", "))
+    }
+}
+
+/// Decodes positional arguments from `json` and applies the constructor, indented for a case
+/// branch at the given level.
+fn decode_arguments(
+    constructor: &str,
+    arguments: &[PursConstructor],
+    json: &str,
+    indent: &str,
+) -> String {
+    if arguments.len() == 1 {
+        return format!("{} <$> {}", constructor, decode_value(&arguments[0], json));
+    }
+
+    let names: Vec<String> = (0..arguments.len()).map(|idx| format!("a{}", idx)).collect();
+    let decoded: Vec<String> = arguments
+        .iter()
+        .zip(names.iter())
+        .map(|(arg, name)| decode_value(arg, name))
+        .collect();
+    format!(
+        "case toArray {json} of\n{indent}  Just [{names}] -> {constructor} <$> {decoded}\n{indent}  _ -> Left (UnexpectedValue {json})",
+        json = json,
+        indent = indent,
+        names = names.join(", "),
+        constructor = constructor,
+        decoded = decoded.join(" <*> "),
+    )
+}
+
+fn pattern(constructor: &PursConstructor) -> String {
+    let mut pattern = constructor.name.clone();
+    for idx in 0..constructor.parameters().len() {
+        pattern.push_str(&format!(" a{}", idx));
+    }
+    if constructor.parameters().is_empty() {
+        pattern
+    } else {
+        format!("({})", pattern)
+    }
+}
+
+/// The `toInt` and `fromInt` functions for enums encoded as their discriminants.
+pub fn int_enum_functions(type_: &PursType) -> Option<String> {
+    let (name, constructors) = match *type_ {
+        PursType::Enum(ref name, ref constructors, _) if is_int_enum(type_) => (name, constructors),
+        _ => return None,
+    };
+    let prefix = function_prefix(name);
+
+    let mut out = format!("{}ToInt :: {} -> Int\n", prefix, name.name);
+    for variant in constructors {
+        out.push_str(&format!(
+            "{}ToInt {} = {}\n",
+            prefix,
+            variant.constructor.name,
+            variant.discriminant.unwrap_or_default()
+        ));
+    }
+
+    out.push_str(&format!(
+        "\n{}FromInt :: Int -> Maybe {}\n",
+        prefix, name.name
+    ));
+    for variant in constructors {
+        out.push_str(&format!(
+            "{}FromInt {} = Just {}\n",
+            prefix,
+            int_literal(variant.discriminant.unwrap_or_default()),
+            variant.constructor.name
+        ));
+    }
+    out.push_str(&format!("{}FromInt _ = Nothing\n", prefix));
+    Some(out)
+}
+
+/// The `EncodeJson` instance for the type.
+pub fn encode_json_instance(type_: &PursType) -> String {
+    let name = type_.constructor();
+    let mut out = format!(
+        "instance encodeJson{} :: {}EncodeJson {} where\n",
+        name.name,
+        constraints("EncodeJson", name),
+        instance_head(name)
+    );
+
+    match *type_ {
+        PursType::Struct(_, ref fields, _) => {
+            out.push_str(&format!(
+                "  encodeJson ({} record) = fromObject (fromFoldable\n",
+                name.name
+            ));
+            for (idx, field) in fields.iter().enumerate() {
+                out.push_str(&format!(
+                    "    {} Tuple \"{}\" ({} record.{})\n",
+                    if idx == 0 { "[" } else { "," },
+                    field.name,
+                    encoder(&field.type_),
+                    field.label(type_.attributes().label_case)
+                ));
+            }
+            if fields.is_empty() {
+                out.push_str("    []\n");
+            } else {
+                out.push_str("    ]\n");
+            }
+            out.push_str("  )\n");
+        }
+        PursType::TupleStruct(_, ref fields, _) => {
+            let constructor = PursConstructor::new(&name.name, None, fields.clone());
+            if fields.is_empty() {
+                out.push_str(&format!("  encodeJson {} = jsonNull\n", name.name));
+            } else {
+                out.push_str(&format!(
+                    "  encodeJson {} = {}\n",
+                    pattern(&constructor),
+                    encode_arguments(fields)
+                ));
+            }
+        }
+        PursType::Enum(_, _, _) if is_int_enum(type_) => {
+            out.push_str(&format!(
+                "  encodeJson value = encodeJson ({}ToInt value)\n",
+                function_prefix(name)
+            ));
+        }
+        // Types without constructors have no values to encode.
+        PursType::Enum(_, ref constructors, _) if constructors.is_empty() => {
+            out.push_str("  encodeJson _ = jsonNull\n");
+        }
+        PursType::Enum(_, ref constructors, _) => {
+            out.push_str("  encodeJson value = case value of\n");
+            for variant in constructors {
+                let constructor = &variant.constructor;
+                if constructor.parameters().is_empty() {
+                    out.push_str(&format!(
+                        "    {} -> fromString \"{}\"\n",
+                        constructor.name, variant.name
+                    ));
+                } else {
+                    out.push_str(&format!(
+                        "    {} -> fromObject (singleton \"{}\" {})\n",
+                        pattern(constructor),
+                        variant.name,
+                        encode_arguments(constructor.parameters())
+                    ));
+                }
+            }
+        }
+        // Values of foreign types are the JSON they were decoded from.
+        PursType::Opaque(_, _) => out.push_str("  encodeJson = unsafeCoerce\n"),
+        PursType::Synonym(_, _, _) => unreachable!("Type synonyms cannot have instances"),
+    }
+
+    out
+}
+
+/// The `DecodeJson` instance for the type.
+pub fn decode_json_instance(type_: &PursType) -> String {
+    let name = type_.constructor();
+    let mut out = format!(
+        "instance decodeJson{} :: {}DecodeJson {} where\n",
+        name.name,
+        constraints("DecodeJson", name),
+        instance_head(name)
+    );
+
+    match *type_ {
+        PursType::Struct(_, ref fields, _) if fields.is_empty() => {
+            out.push_str(&format!(
+                "  decodeJson json = case toObject json of\n    Just _ -> Right ({} {{}})\n    Nothing -> Left (TypeMismatch \"Object\")\n",
+                name.name
+            ));
+        }
+        PursType::Struct(_, ref fields, _) => {
+            // Fields are bound to fresh names, since labels can be keywords such as `data`.
+            out.push_str("  decodeJson json = do\n    object <- decodeJson json\n");
+            let mut record_fields = Vec::new();
+            for (idx, field) in fields.iter().enumerate() {
+                out.push_str(&format!(
+                    "    v{} <- {}\n",
+                    idx,
+                    decode_field(&field.name, &field.type_)
+                ));
+                record_fields.push(format!(
+                    "{}: v{}",
+                    field.label(type_.attributes().label_case),
+                    idx
+                ));
+            }
+            out.push_str(&format!(
+                "    pure ({} {{ {} }})\n",
+                name.name,
+                record_fields.join(", ")
+            ));
+        }
+        PursType::TupleStruct(_, ref fields, _) if fields.is_empty() => {
+            out.push_str(&format!(
+                "  decodeJson json\n    | isNull json = Right {}\n    | otherwise = Left (TypeMismatch \"Null\")\n",
+                name.name
+            ));
+        }
+        PursType::TupleStruct(_, ref fields, _) => {
+            out.push_str(&format!(
+                "  decodeJson json = {}\n",
+                decode_arguments(&name.name, fields, "json", "  ")
+            ));
+        }
+        PursType::Enum(_, _, _) if is_int_enum(type_) => {
+            out.push_str(&format!(
+                "  decodeJson json = do\n    n <- decodeJson json\n    case {}FromInt n of\n      Just value -> Right value\n      Nothing -> Left (UnexpectedValue json)\n",
+                function_prefix(name)
+            ));
+        }
+        PursType::Enum(_, ref constructors, _) => {
+            let (units, others): (Vec<&PursVariant>, Vec<&PursVariant>) = constructors
+                .iter()
+                .partition(|c| c.constructor.parameters().is_empty());
+            let mut indent = "  ".to_string();
+            out.push_str("  decodeJson json =");
+
+            if !units.is_empty() {
+                out.push_str(" case toString json of\n");
+                for variant in &units {
+                    out.push_str(&format!(
+                        "    Just \"{}\" -> Right {}\n",
+                        variant.name, variant.constructor.name
+                    ));
+                }
+                out.push_str("    _ ->");
+                indent.push_str("  ");
+            }
+
+            if others.is_empty() {
+                out.push_str(" Left (UnexpectedValue json)\n");
+            } else {
+                out.push_str(" case map toUnfoldable (toObject json) of\n");
+                for variant in &others {
+                    let constructor = &variant.constructor;
+                    out.push_str(&format!(
+                        "{}  Just [Tuple \"{}\" value] -> {}\n",
+                        indent,
+                        variant.name,
+                        decode_arguments(
+                            &constructor.name,
+                            constructor.parameters(),
+                            "value",
+                            &format!("{}  ", indent)
+                        )
+                    ));
+                }
+                out.push_str(&format!("{}  _ -> Left (UnexpectedValue json)\n", indent));
+            }
+        }
+        PursType::Opaque(_, _) => out.push_str("  decodeJson = Right <<< unsafeCoerce\n"),
+        PursType::Synonym(_, _, _) => unreachable!("Type synonyms cannot have instances"),
+    }
+
+    out
+}
@@ -0,0 +1,87 @@
+//! Dates and times, sent as RFC 3339 / ISO 8601 strings.
+
+use purs_constructor::*;
+use purs_type::*;
+
+/// How dates and times, e.g. chrono's or the time crate's, are represented.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Dates {
+    /// `String`, the default.
+    #[default]
+    String,
+    /// The `Date`, `Time` and `DateTime` types from `purescript-datetime`. Dates and times without
+    /// an offset are `NaiveDateTime`s, a synonym for `DateTime` declared in the module. Generated
+    /// codecs read and write the formats of chrono's serde implementations, of the time crate's
+    /// `Date` and `Time`, and of `time::serde::rfc3339`: dates and times with an offset are
+    /// converted to UTC, and written with a `Z` suffix. The time crate's `OffsetDateTime` and
+    /// `PrimitiveDateTime` stay `String`s, unless serialized with `time::serde::rfc3339`.
+    DateTime,
+}
+
+/// A date and time with an offset sent as an RFC 3339 string, following the module's `Dates`
+/// policy.
+///
+/// Use it as a field type. Fields serialized with `time::serde::rfc3339` derive as it: the time
+/// crate's default serde format for `OffsetDateTime` isn't RFC 3339, so those are `String`s.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rfc3339DateTime<T = ()>(pub T);
+
+impl<T> AsPursConstructor for Rfc3339DateTime<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        date_constructor(Marker::DateTime)
+    }
+}
+
+/// The constructor for dates and times with the given marker, displayed as a `String` until a
+/// module applies its policy.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn date_constructor(marker: Marker) -> PursConstructor {
+    PursConstructor::new("String", Some("PRIM"), vec![]).with_marker(marker)
+}
+
+/// Replaces the dates and times in the type with the policy's representation.
+pub fn apply_policy(type_: &mut PursConstructor, policy: Dates) {
+    let rendered = match (policy, type_.marker()) {
+        (Dates::String, Some(&Marker::Date))
+        | (Dates::String, Some(&Marker::Time))
+        | (Dates::String, Some(&Marker::DateTime))
+        | (Dates::String, Some(&Marker::NaiveDateTime)) => Some(("String", Some("PRIM"))),
+        (Dates::DateTime, Some(&Marker::Date)) => Some(("Date", Some("Data.Date"))),
+        (Dates::DateTime, Some(&Marker::Time)) => Some(("Time", Some("Data.Time"))),
+        (Dates::DateTime, Some(&Marker::DateTime)) => Some(("DateTime", Some("Data.DateTime"))),
+        (Dates::DateTime, Some(&Marker::NaiveDateTime)) => Some(("NaiveDateTime", None)),
+        _ => None,
+    };
+    if let Some((name, module)) = rendered {
+        type_.name = name.to_string();
+        type_.module = module.map(str::to_string);
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, policy);
+    }
+}
+
+/// Whether the type is the `NaiveDateTime` synonym declared in the module.
+pub fn is_naive_date_time(type_: &PursConstructor) -> bool {
+    type_.marker() == Some(&Marker::NaiveDateTime) && type_.module.is_none()
+}
+
+/// The imports needed by `local_synonyms`, in the format of `PursModule`'s imports.
+pub const LOCAL_SYNONYM_IMPORTS: &[(&str, &[&str])] = &[("Data.DateTime", &["DateTime"])];
+
+/// The declaration of the `NaiveDateTime` synonym, if the module uses it.
+pub fn local_synonyms(types: &[PursType]) -> Vec<String> {
+    fn mentions(type_: &PursConstructor) -> bool {
+        is_naive_date_time(type_) || type_.parameters().iter().any(mentions)
+    }
+
+    if types
+        .iter()
+        .any(|type_| type_.field_types().into_iter().any(mentions))
+    {
+        vec!["-- | A date and time without an offset.\ntype NaiveDateTime = DateTime\n".to_string()]
+    } else {
+        Vec::new()
+    }
+}
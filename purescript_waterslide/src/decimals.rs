@@ -0,0 +1,38 @@
+//! Arbitrary-precision decimals, e.g. `rust_decimal::Decimal`.
+
+use purs_constructor::*;
+
+/// How arbitrary-precision decimals serialized as strings are represented. Set it for a module
+/// with `PursModule::with_decimals`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Decimals {
+    /// `String`, the default.
+    #[default]
+    String,
+    /// `Decimal` from `purescript-decimals`. Generated codecs read JSON strings and numbers, and
+    /// write JSON strings.
+    Decimal,
+}
+
+/// The constructor for decimals serialized as strings, displayed as a `String` until a module
+/// applies its policy.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn decimal() -> PursConstructor {
+    PursConstructor::new("String", Some("PRIM"), vec![]).with_marker(Marker::Decimal)
+}
+
+/// Replaces the decimals in the type with the policy's representation.
+pub fn apply_policy(type_: &mut PursConstructor, policy: Decimals) {
+    if type_.marker() == Some(&Marker::Decimal) {
+        let (name, module) = match policy {
+            Decimals::String => ("String", "PRIM"),
+            Decimals::Decimal => ("Decimal", "Data.Decimal"),
+        };
+        type_.name = name.to_string();
+        type_.module = Some(module.to_string());
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, policy);
+    }
+}
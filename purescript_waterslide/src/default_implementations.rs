@@ -1,42 +1,115 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::convert::Infallible;
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+               NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
 use purs_constructor::*;
+use integers;
+use tuples;
 
 impl<T: AsPursConstructor> AsPursConstructor for Vec<T> {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            name: "Array".to_string(),
-            module: None,
-            parameters: vec![<T as AsPursConstructor>::as_purs_constructor()],
-        }
+        PursConstructor::new(
+            "Array",
+            None,
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
     }
 }
 
 impl<'a, T: AsPursConstructor> AsPursConstructor for &'a [T] {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            name: "Array".to_string(),
-            module: None,
-            parameters: vec![<T as AsPursConstructor>::as_purs_constructor()],
+        PursConstructor::new(
+            "Array",
+            None,
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
+impl<T: AsPursConstructor> AsPursConstructor for [T] {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Array",
+            None,
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
+impl<T: AsPursConstructor, const N: usize> AsPursConstructor for [T; N] {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Array",
+            None,
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
+/// Sequences serde serializes as JSON arrays.
+macro_rules! purs_array_impl {
+    ($rust_type:ident) => {
+        impl<T: AsPursConstructor> AsPursConstructor for $rust_type<T> {
+            fn as_purs_constructor() -> PursConstructor {
+                PursConstructor::new(
+                    "Array",
+                    None,
+                    vec![<T as AsPursConstructor>::as_purs_constructor()],
+                )
+            }
         }
     }
 }
 
+purs_array_impl!(VecDeque);
+purs_array_impl!(LinkedList);
+purs_array_impl!(BinaryHeap);
+
 impl<T: AsPursConstructor> AsPursConstructor for Option<T> {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            name: "Maybe".to_string(),
-            module: Some("Data.Maybe".to_string()),
-            parameters: vec![<T as AsPursConstructor>::as_purs_constructor()],
-        }
+        PursConstructor::new(
+            "Maybe",
+            Some("Data.Maybe"),
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
+impl<T, E> AsPursConstructor for Result<T, E>
+where
+    T: AsPursConstructor,
+    E: AsPursConstructor,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Either",
+            Some("Data.Either"),
+            vec![
+                <E as AsPursConstructor>::as_purs_constructor(),
+                <T as AsPursConstructor>::as_purs_constructor(),
+            ],
+        )
+    }
+}
+
+impl AsPursConstructor for str {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
     }
 }
 
 impl<'a> AsPursConstructor for &'a str {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            name: "String".to_string(),
-            module: None,
-            parameters: vec![],
-        }
+        <String as AsPursConstructor>::as_purs_constructor()
     }
 }
 
@@ -46,28 +119,48 @@ where
     U: AsPursConstructor,
 {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            name: "Tuple".to_string(),
-            module: Some("Data.Tuple".to_string()),
-            parameters: vec![
+        PursConstructor::new(
+            "Tuple",
+            Some("Data.Tuple"),
+            vec![
                 <T as AsPursConstructor>::as_purs_constructor(),
                 <U as AsPursConstructor>::as_purs_constructor(),
             ],
+        )
+    }
+}
+
+/// Larger tuples are `Data.Tuple.Nested` synonyms, encoded as JSON arrays like serde does.
+macro_rules! purs_tuple_impl {
+    ($($param:ident),*) => {
+        impl<$($param: AsPursConstructor),*> AsPursConstructor for ($($param,)*) {
+            fn as_purs_constructor() -> PursConstructor {
+                tuples::tuple_constructor(vec![
+                    $( <$param as AsPursConstructor>::as_purs_constructor() ),*
+                ])
+            }
         }
     }
 }
 
+purs_tuple_impl!(A, B, C);
+purs_tuple_impl!(A, B, C, D);
+purs_tuple_impl!(A, B, C, D, E);
+purs_tuple_impl!(A, B, C, D, E, F);
+purs_tuple_impl!(A, B, C, D, E, F, G);
+purs_tuple_impl!(A, B, C, D, E, F, G, H);
+purs_tuple_impl!(A, B, C, D, E, F, G, H, I);
+purs_tuple_impl!(A, B, C, D, E, F, G, H, I, J);
+purs_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K);
+purs_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 impl AsPursConstructor for () {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: Some("Prelude".to_string()),
-            name: "Tuple".to_string(),
-            parameters: vec![],
-        }
+        PursConstructor::new("Unit", Some("Prelude"), vec![])
     }
 }
 
-impl<T: AsPursConstructor> AsPursConstructor for Box<T> {
+impl<T: AsPursConstructor + ?Sized> AsPursConstructor for Box<T> {
     fn as_purs_constructor() -> PursConstructor {
         T::as_purs_constructor()
     }
@@ -79,15 +172,159 @@ impl<'a, T: AsPursConstructor> AsPursConstructor for &'a T {
     }
 }
 
+impl<'a, B> AsPursConstructor for Cow<'a, B>
+where
+    B: AsPursConstructor + ToOwned + ?Sized,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        B::as_purs_constructor()
+    }
+}
+
+/// Wrappers serde serializes as their contents.
+macro_rules! purs_transparent_impl {
+    ($rust_type:ident) => {
+        impl<T: AsPursConstructor + ?Sized> AsPursConstructor for $rust_type<T> {
+            fn as_purs_constructor() -> PursConstructor {
+                T::as_purs_constructor()
+            }
+        }
+    }
+}
+
+purs_transparent_impl!(Rc);
+purs_transparent_impl!(Arc);
+purs_transparent_impl!(Cell);
+purs_transparent_impl!(RefCell);
+purs_transparent_impl!(Mutex);
+purs_transparent_impl!(RwLock);
+
+/// Record fields of this type are left out of the derived records, since it carries no data. It
+/// is `Unit` elsewhere, as serde serializes it as `null`.
+impl<T: ?Sized> AsPursConstructor for PhantomData<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        <() as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for Infallible {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new("Void", Some("Data.Void"), vec![])
+    }
+}
+
+// Their nanoseconds are below 10^9, so they fit in an `Int` although they are `u32`s.
+impl AsPursConstructor for Duration {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::record(vec![
+            ("secs".to_string(), u64::as_purs_constructor()),
+            ("nanos".to_string(), i32::as_purs_constructor()),
+        ])
+    }
+}
+
+impl AsPursConstructor for SystemTime {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::record(vec![
+            ("secs_since_epoch".to_string(), u64::as_purs_constructor()),
+            ("nanos_since_epoch".to_string(), i32::as_purs_constructor()),
+        ])
+    }
+}
+
+/// Types serde serializes like the given primitive.
+macro_rules! purs_alias_impl {
+    ($rust_type:ty, $primitive:ty) => {
+        impl AsPursConstructor for $rust_type {
+            fn as_purs_constructor() -> PursConstructor {
+                <$primitive as AsPursConstructor>::as_purs_constructor()
+            }
+        }
+    }
+}
+
+purs_alias_impl!(NonZeroI8, i8);
+purs_alias_impl!(NonZeroI16, i16);
+purs_alias_impl!(NonZeroI32, i32);
+purs_alias_impl!(NonZeroI64, i64);
+purs_alias_impl!(NonZeroI128, i128);
+purs_alias_impl!(NonZeroIsize, isize);
+purs_alias_impl!(NonZeroU8, u8);
+purs_alias_impl!(NonZeroU16, u16);
+purs_alias_impl!(NonZeroU32, u32);
+purs_alias_impl!(NonZeroU64, u64);
+purs_alias_impl!(NonZeroU128, u128);
+purs_alias_impl!(NonZeroUsize, usize);
+
+purs_alias_impl!(IpAddr, String);
+purs_alias_impl!(Ipv4Addr, String);
+purs_alias_impl!(Ipv6Addr, String);
+purs_alias_impl!(SocketAddr, String);
+purs_alias_impl!(SocketAddrV4, String);
+purs_alias_impl!(SocketAddrV6, String);
+purs_alias_impl!(PathBuf, String);
+purs_alias_impl!(Path, String);
+
+/// serde_json encodes every map as a JSON object, so maps with string keys are `Object`s. Other
+/// keys are stringified in JSON, and their maps are `Map`s.
+fn map_constructor(key: PursConstructor, value: PursConstructor) -> PursConstructor {
+    if key.name == "String" && key.parameters().is_empty() {
+        PursConstructor::new("Object", Some("Foreign.Object"), vec![value])
+    } else {
+        PursConstructor::new("Map", Some("Data.Map"), vec![key, value])
+    }
+}
+
+impl<K, V, S> AsPursConstructor for HashMap<K, V, S>
+where
+    K: AsPursConstructor,
+    V: AsPursConstructor,
+    S: BuildHasher,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        map_constructor(K::as_purs_constructor(), V::as_purs_constructor())
+    }
+}
+
+impl<K, V> AsPursConstructor for BTreeMap<K, V>
+where
+    K: AsPursConstructor,
+    V: AsPursConstructor,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        map_constructor(K::as_purs_constructor(), V::as_purs_constructor())
+    }
+}
+
+impl<T, S> AsPursConstructor for HashSet<T, S>
+where
+    T: AsPursConstructor,
+    S: BuildHasher,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Set",
+            Some("Data.Set"),
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
+impl<T: AsPursConstructor> AsPursConstructor for BTreeSet<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Set",
+            Some("Data.Set"),
+            vec![<T as AsPursConstructor>::as_purs_constructor()],
+        )
+    }
+}
+
 macro_rules! purs_primitive_impl {
     ($rust_type:ty, $purs_type:expr, $import:expr) => {
         impl AsPursConstructor for $rust_type {
             fn as_purs_constructor() -> PursConstructor {
-                PursConstructor {
-                    module: Some($import.to_string()),
-                    name: $purs_type.to_string(),
-                    parameters: vec![],
-                }
+                PursConstructor::new($purs_type, Some($import), vec![])
             }
         }
     }
@@ -100,16 +337,32 @@ purs_primitive_impl!(bool, "Boolean", PRIM);
 purs_primitive_impl!(i8, "Int", PRIM);
 purs_primitive_impl!(i16, "Int", PRIM);
 purs_primitive_impl!(i32, "Int", PRIM);
-purs_primitive_impl!(i64, "Int", PRIM);
-purs_primitive_impl!(isize, "Int", PRIM);
 
 purs_primitive_impl!(u8, "Int", PRIM);
 purs_primitive_impl!(u16, "Int", PRIM);
-purs_primitive_impl!(u32, "Int", PRIM);
-purs_primitive_impl!(u64, "Int", PRIM);
-purs_primitive_impl!(usize, "Int", PRIM);
+
+/// Integers that don't fit in an `Int`, represented according to the module's `WideIntegers`
+/// policy.
+macro_rules! purs_wide_integer_impl {
+    ($rust_type:ty) => {
+        impl AsPursConstructor for $rust_type {
+            fn as_purs_constructor() -> PursConstructor {
+                integers::wide_integer()
+            }
+        }
+    }
+}
+
+purs_wide_integer_impl!(u32);
+purs_wide_integer_impl!(i64);
+purs_wide_integer_impl!(i128);
+purs_wide_integer_impl!(isize);
+purs_wide_integer_impl!(u64);
+purs_wide_integer_impl!(u128);
+purs_wide_integer_impl!(usize);
 
 purs_primitive_impl!(f32, "Number", PRIM);
 purs_primitive_impl!(f64, "Number", PRIM);
 
+purs_primitive_impl!(char, "Char", PRIM);
 purs_primitive_impl!(String, "String", PRIM);
@@ -0,0 +1,41 @@
+//! Integers wider than Purescript's 32-bit `Int`.
+
+use purs_constructor::*;
+
+/// How `u32`, 64-bit and 128-bit integers, `isize` and `usize` are represented, since they don't
+/// fit in a Purescript `Int`, as do their `NonZero` counterparts. Set it for a module with
+/// `PursModule::with_wide_integers`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WideIntegers {
+    /// `Number`, the default. Integers above 2^53 lose precision, as they do in `JSON.parse`.
+    #[default]
+    Number,
+    /// `BigInt` from `purescript-js-bigints`, for integers serialized as strings, e.g. with
+    /// serde_with's `DisplayFromStr`. Generated codecs write JSON strings, so integers above 2^53
+    /// keep their precision, and also read JSON numbers.
+    BigInt,
+    /// `String`, for integers serialized as strings, e.g. with serde_with's `DisplayFromStr`.
+    String,
+}
+
+/// The constructor for wide integers, displayed as a `Number` until a module applies its policy.
+pub fn wide_integer() -> PursConstructor {
+    PursConstructor::new("Number", Some("PRIM"), vec![]).with_marker(Marker::WideInteger)
+}
+
+/// Replaces the wide integers in the type with the policy's representation.
+pub fn apply_policy(type_: &mut PursConstructor, policy: WideIntegers) {
+    if type_.marker() == Some(&Marker::WideInteger) {
+        let (name, module) = match policy {
+            WideIntegers::Number => ("Number", "PRIM"),
+            WideIntegers::BigInt => ("BigInt", "JS.BigInt"),
+            WideIntegers::String => ("String", "PRIM"),
+        };
+        type_.name = name.to_string();
+        type_.module = Some(module.to_string());
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, policy);
+    }
+}
@@ -0,0 +1,37 @@
+//! Arbitrary JSON values, e.g. `serde_json::Value`.
+
+use purs_constructor::*;
+
+/// How arbitrary JSON values are represented. Set it for a module with
+/// `PursModule::with_json_values`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonValues {
+    /// `Json` from `purescript-argonaut-core`, the default.
+    #[default]
+    Argonaut,
+    /// `Foreign` from `purescript-foreign`, for projects decoding JSON with `purescript-simple-json`.
+    Foreign,
+}
+
+/// The constructor for JSON values, displayed as Argonaut's `Json` until a module applies its
+/// policy.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn json_value() -> PursConstructor {
+    PursConstructor::new("Json", Some("Data.Argonaut.Core"), vec![]).with_marker(Marker::Json)
+}
+
+/// Replaces the JSON values in the type with the policy's representation.
+pub fn apply_policy(type_: &mut PursConstructor, policy: JsonValues) {
+    if type_.marker() == Some(&Marker::Json) {
+        let (name, module) = match policy {
+            JsonValues::Argonaut => ("Json", "Data.Argonaut.Core"),
+            JsonValues::Foreign => ("Foreign", "Foreign"),
+        };
+        type_.name = name.to_string();
+        type_.module = Some(module.to_string());
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, policy);
+    }
+}
@@ -0,0 +1,226 @@
+//! Rendering of `purescript-profunctor-lenses` optics for the types in a module.
+
+use std::collections::BTreeSet;
+use purs_constructor::*;
+use purs_type::*;
+
+/// The imports needed by the generated optics, in the format of `PursModule`'s imports.
+pub const LENS_IMPORTS: &[(&str, &[&str])] = &[
+    ("Prelude", &[]),
+    ("Data.Lens", &["Iso'", "Lens", "Prism'", "iso", "prism'"]),
+    ("Data.Lens.Record", &["prop"]),
+    ("Data.Maybe", &["Maybe(..)"]),
+    ("Type.Proxy", &["Proxy(..)"]),
+];
+
+/// Argument names for constructors with multiple arguments, as used by purescript-bridge.
+const ARGUMENT_NAMES: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn forall(type_: &PursConstructor) -> String {
+    if type_.parameters().is_empty() {
+        String::new()
+    } else {
+        let params: Vec<&str> = type_.parameters().iter().map(|p| p.name.as_str()).collect();
+        format!("forall {}. ", params.join(" "))
+    }
+}
+
+fn parenthesized(type_: &PursConstructor) -> String {
+    if type_.is_application() {
+        format!("({})", type_)
+    } else {
+        format!("{}", type_)
+    }
+}
+
+fn record_type<'a, I>(fields: I) -> String
+where
+    I: Iterator<Item = (String, &'a PursConstructor)>,
+{
+    let fields: Vec<String> = fields
+        .map(|(name, type_)| format!("{} :: {}", name, type_))
+        .collect();
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    format!("{{ {} }}", fields.join(", "))
+}
+
+fn argument_names(count: usize) -> Vec<String> {
+    let letters: Vec<char> = ARGUMENT_NAMES.chars().collect();
+    (0..count)
+        .map(|idx| match idx / letters.len() {
+            0 => letters[idx].to_string(),
+            n => format!("{}{}", letters[idx % letters.len()], n),
+        })
+        .collect()
+}
+
+/// An identifier for an optic named after a label, which drops the characters that can't appear
+/// in identifiers and capitalizes the letter following them: `pageNum` for `page-num`.
+fn identifier(label: &str) -> String {
+    let mut identifier = String::new();
+    let mut capitalize = false;
+    for c in label.chars() {
+        if c.is_alphanumeric() || c == '_' || c == '\'' {
+            if capitalize && !identifier.is_empty() {
+                identifier.extend(c.to_uppercase());
+            } else {
+                identifier.push(c);
+            }
+            capitalize = false;
+        } else {
+            capitalize = true;
+        }
+    }
+    identifier
+}
+
+/// The name of an optic: the first candidate no other optic of the module is named after, else the
+/// last candidate followed by primes. Optics are named after their candidates with an underscore
+/// prefix.
+fn optic_name(candidates: &[&str], defined: &mut BTreeSet<String>) -> String {
+    let name = match candidates.iter().find(|name| !defined.contains(**name)) {
+        Some(name) => name.to_string(),
+        None => {
+            let mut name = candidates[candidates.len() - 1].to_string();
+            while defined.contains(&name) {
+                name.push('\'');
+            }
+            name
+        }
+    };
+    defined.insert(name.clone());
+    format!("_{}", name)
+}
+
+/// A prism named `optic` focusing on one of the type's constructors. When it is the only
+/// constructor, the fallback case would be redundant and is omitted.
+fn prism(
+    optic: &str,
+    type_: &PursConstructor,
+    constructor: &PursConstructor,
+    only_constructor: bool,
+) -> String {
+    let fallback = if only_constructor {
+        ""
+    } else {
+        "  _ -> Nothing\n"
+    };
+    let signature = format!(
+        "{} :: {}Prism' {} ",
+        optic,
+        forall(type_),
+        parenthesized(type_)
+    );
+    let arguments = constructor.parameters();
+
+    match arguments.len() {
+        0 => format!(
+            "{sig}Unit\n{optic} = prism' (const {name}) $ case _ of\n  {name} -> Just unit\n{fallback}",
+            sig = signature,
+            optic = optic,
+            fallback = fallback,
+            name = constructor.name
+        ),
+        1 => format!(
+            "{sig}{arg}\n{optic} = prism' {name} $ case _ of\n  {name} a -> Just a\n{fallback}",
+            sig = signature,
+            optic = optic,
+            fallback = fallback,
+            arg = parenthesized(&arguments[0]),
+            name = constructor.name
+        ),
+        _ => {
+            let names = argument_names(arguments.len());
+            let record_fields = names.iter().cloned().zip(arguments.iter());
+            format!(
+                "{sig}{record}\n{optic} = prism' (\\{{ {names} }} -> {name} {args}) $ case _ of\n  {name} {args} -> Just {{ {names} }}\n{fallback}",
+                sig = signature,
+                optic = optic,
+                fallback = fallback,
+                record = record_type(record_fields),
+                name = constructor.name,
+                names = names.join(", "),
+                args = names.join(" ")
+            )
+        }
+    }
+}
+
+/// The optics for the type itself: an `Iso'` for records and newtypes, and a `Prism'` per
+/// constructor for enums. Optics whose name is already in `defined` are qualified with the name of
+/// the type, e.g. `_ActorUser` for the `User` constructor of `Actor`, and their names are added to
+/// `defined`.
+pub fn type_optics(type_: &PursType, defined: &mut BTreeSet<String>) -> Vec<String> {
+    let constructor = type_.constructor();
+    let mut optics = Vec::new();
+
+    match *type_ {
+        PursType::Struct(_, _, _) if type_.is_type_synonym() => (),
+        PursType::Struct(_, ref fields, _) => {
+            optics.push(format!(
+                "{optic} :: {forall}Iso' {type_} {record}\n{optic} = iso (\\({name} r) -> r) {name}\n",
+                optic = optic_name(&[&constructor.name], defined),
+                name = constructor.name,
+                forall = forall(constructor),
+                type_ = parenthesized(constructor),
+                record = record_type(fields.iter().map(|f| {
+                    (f.label(type_.attributes().label_case), &f.type_)
+                }))
+            ));
+        }
+        PursType::TupleStruct(_, ref fields, _) if fields.len() == 1 => {
+            optics.push(format!(
+                "{optic} :: {forall}Iso' {type_} {inner}\n{optic} = iso (\\({name} a) -> a) {name}\n",
+                optic = optic_name(&[&constructor.name], defined),
+                name = constructor.name,
+                forall = forall(constructor),
+                type_ = parenthesized(constructor),
+                inner = parenthesized(&fields[0])
+            ));
+        }
+        PursType::TupleStruct(_, _, _)
+        | PursType::Opaque(_, _)
+        | PursType::Synonym(_, _, _) => (),
+        PursType::Enum(_, ref constructors, _) => {
+            for variant in constructors.iter().map(|c| &c.constructor) {
+                let optic = optic_name(
+                    &[
+                        &variant.name,
+                        &format!("{}{}", constructor.name, variant.name),
+                    ],
+                    defined,
+                );
+                optics.push(prism(&optic, constructor, variant, constructors.len() == 1));
+            }
+        }
+    }
+
+    optics
+}
+
+/// One polymorphic lens per distinct record field name in the module, usable on any record with
+/// that field. Lenses whose name is already in `defined` are qualified, e.g. `_fieldUser` for a
+/// `User` field.
+pub fn field_lenses(types: &[PursType], defined: &mut BTreeSet<String>) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    for type_ in types {
+        if let PursType::Struct(_, ref fields, ref attributes) = *type_ {
+            names.extend(fields.iter().map(|f| f.label_name(attributes.label_case)));
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let optic = identifier(&name);
+            format!(
+                "{optic} :: forall a b r. Lens {{ {label} :: a | r }} {{ {label} :: b | r }} a b\n{optic} = prop (Proxy :: Proxy \"{name}\")\n",
+                optic = optic_name(&[&optic, &format!("field{}", optic)], defined),
+                label = quote_label(&name),
+                name = name
+            )
+        })
+        .collect()
+}
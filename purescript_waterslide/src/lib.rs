@@ -4,12 +4,33 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod arbitrary;
+mod big_integers;
+mod bytes;
+mod codecs;
+mod dates;
+mod decimals;
 mod default_implementations;
+mod integers;
+mod json;
+mod lenses;
+#[macro_use]
+mod macros;
+mod newtypes;
+mod ordered_maps;
 mod purs_constructor;
 mod purs_module;
 mod purs_type;
+mod synonyms;
 mod third_party;
+mod tuples;
 
+pub use big_integers::BigIntegers;
+pub use bytes::{ArrayBufferBytes, Base64Bytes};
+pub use dates::{Dates, Rfc3339DateTime};
+pub use decimals::Decimals;
+pub use integers::WideIntegers;
+pub use json::JsonValues;
 pub use purs_constructor::*;
 pub use purs_type::*;
 pub use purs_module::*;
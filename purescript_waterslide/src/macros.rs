@@ -0,0 +1,174 @@
+//! Macros implementing `AsPursConstructor` and `AsPursType` by hand, for types with a custom
+//! serialization.
+
+/// Implements `AsPursConstructor` for a type serialized as a Purescript type without parameters,
+/// optionally imported from a module.
+///
+/// `purs_primitive!(SessionToken => "String");`
+///
+/// `purs_primitive!(Timestamp => "Instant" in "Data.DateTime.Instant");`
+#[macro_export]
+macro_rules! purs_primitive {
+    ( $rust_type:ty => $name:tt ) => {
+        impl $crate::AsPursConstructor for $rust_type {
+            fn as_purs_constructor() -> $crate::PursConstructor {
+                $crate::PursConstructor::new($name, None, vec![])
+            }
+        }
+    };
+    ( $rust_type:ty => $name:tt in $module:tt ) => {
+        impl $crate::AsPursConstructor for $rust_type {
+            fn as_purs_constructor() -> $crate::PursConstructor {
+                $crate::PursConstructor::new($name, Some($module), vec![])
+            }
+        }
+    };
+}
+
+/// Implements `AsPursConstructor` for a type declared in the generated module, named like the
+/// Rust type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! purs_declared_constructor {
+    ( $name:ident ) => {
+        impl $crate::AsPursConstructor for $name {
+            fn as_purs_constructor() -> $crate::PursConstructor {
+                $crate::PursConstructor::new(stringify!($name), None, vec![])
+            }
+        }
+    };
+}
+
+/// Implements `AsPursConstructor` and `AsPursType` for a type serialized as another, like a
+/// newtype struct: `purs_newtype!(UserId(String));` declares `newtype UserId = UserId String`.
+#[macro_export]
+macro_rules! purs_newtype {
+    ( $name:ident ( $inner:ty ) ) => {
+        $crate::purs_declared_constructor!($name);
+
+        impl $crate::AsPursType for $name {
+            fn as_purs_type() -> $crate::PursType {
+                $crate::PursType::TupleStruct(
+                    <$name as $crate::AsPursConstructor>::as_purs_constructor(),
+                    vec![<$inner as $crate::AsPursConstructor>::as_purs_constructor()],
+                    $crate::PursAttributes {
+                        representation: Some($crate::RecordRepresentation::Newtype),
+                        ..$crate::PursAttributes::default()
+                    },
+                )
+            }
+        }
+    };
+}
+
+/// Implements `AsPursConstructor` and `AsPursType` for a type serialized as a JSON object with the
+/// given fields.
+///
+/// `purs_record!(Point { x: f64, y: f64 });` declares `data Point = Point { x :: Number, y ::
+/// Number }`.
+#[macro_export]
+macro_rules! purs_record {
+    ( $name:ident { $( $field:ident : $type_:ty ),* $(,)* } ) => {
+        $crate::purs_declared_constructor!($name);
+
+        impl $crate::AsPursType for $name {
+            fn as_purs_type() -> $crate::PursType {
+                $crate::PursType::Struct(
+                    <$name as $crate::AsPursConstructor>::as_purs_constructor(),
+                    vec![
+                        $( $crate::PursField {
+                            name: stringify!($field).to_string(),
+                            type_: <$type_ as $crate::AsPursConstructor>::as_purs_constructor(),
+                            doc: None,
+                        } ),*
+                    ],
+                    $crate::PursAttributes::default(),
+                )
+            }
+        }
+    };
+}
+
+/// Implements `AsPursConstructor` and `AsPursType` for a type serialized like an enum with the
+/// given variants, and the types of their fields.
+///
+/// `purs_enum!(Shape { Circle(f64), Rectangle(f64, f64), Empty });` declares `data Shape = Circle
+/// Number | Rectangle Number Number | Empty`.
+#[macro_export]
+macro_rules! purs_enum {
+    ( $name:ident { $( $variant:ident $( ( $( $argument:ty ),* ) )* ),* $(,)* } ) => {
+        $crate::purs_declared_constructor!($name);
+
+        impl $crate::AsPursType for $name {
+            fn as_purs_type() -> $crate::PursType {
+                $crate::PursType::Enum(
+                    <$name as $crate::AsPursConstructor>::as_purs_constructor(),
+                    vec![
+                        $( $crate::PursVariant {
+                            name: stringify!($variant).to_string(),
+                            constructor: $crate::PursConstructor::new(
+                                stringify!($variant),
+                                None,
+                                vec![
+                                    $( $(
+                                        <$argument as $crate::AsPursConstructor>
+                                            ::as_purs_constructor(),
+                                    )* )*
+                                ],
+                            ),
+                            discriminant: None,
+                            doc: None,
+                        } ),*
+                    ],
+                    $crate::PursAttributes::default(),
+                )
+            }
+        }
+    };
+}
+
+/// A `PursType::Synonym` for a Rust type alias, named like it, to include in a module. The
+/// parameters of generic aliases are named like the alias's.
+///
+/// `purs_alias!(UserMap)` declares `type UserMap = Map UserId User` for `type UserMap =
+/// HashMap<UserId, User>;`, and `purs_alias!(Lookup<T>)` declares `type Lookup t = Object t` for
+/// `type Lookup<T> = HashMap<String, T>;`. Fields marked `#[purs(synonym)]` refer to the synonym
+/// by name.
+///
+/// Synonyms are never imported: include them in the `purs_module!` of the types referring to
+/// them, which would otherwise mention an undeclared type.
+#[macro_export]
+macro_rules! purs_alias {
+    ( $alias:ident ) => {
+        $crate::PursType::Synonym(
+            $crate::PursConstructor::new(stringify!($alias), None, vec![]),
+            <$alias as $crate::AsPursConstructor>::as_purs_constructor(),
+            $crate::PursAttributes::default(),
+        )
+    };
+    ( $alias:ident < $( $param:ident ),* > ) => {
+        {
+            $(
+                struct $param;
+
+                impl $crate::AsPursConstructor for $param {
+                    fn as_purs_constructor() -> $crate::PursConstructor {
+                        $crate::PursConstructor::variable(&stringify!($param).to_lowercase())
+                    }
+                }
+            )*
+
+            $crate::PursType::Synonym(
+                $crate::PursConstructor::new(
+                    stringify!($alias),
+                    None,
+                    vec![
+                        $( <$param as $crate::AsPursConstructor>::as_purs_constructor() ),*
+                    ],
+                ),
+                <$alias<$( $param ),*> as $crate::AsPursConstructor>::as_purs_constructor(),
+                $crate::PursAttributes::default(),
+            )
+        }
+    };
+}
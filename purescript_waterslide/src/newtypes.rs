@@ -0,0 +1,89 @@
+//! Identifiers serialized as strings, like URLs, which can be told apart from other strings with
+//! newtypes declared in the generated module.
+
+use purs_constructor::*;
+use purs_type::*;
+
+/// The constructor for an identifier with the given newtype name, displayed as a `String` until a
+/// module renders it.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn string_newtype(name: &str) -> PursConstructor {
+    PursConstructor::new("String", Some("PRIM"), vec![])
+        .with_marker(Marker::StringNewtype(name.to_string()))
+}
+
+/// Replaces the identifiers in the type with their newtype, or with a `String`.
+pub fn apply_policy(type_: &mut PursConstructor, newtypes: bool) {
+    let newtype = match type_.marker() {
+        Some(Marker::StringNewtype(name)) => Some(name.clone()),
+        _ => None,
+    };
+    if let Some(newtype) = newtype {
+        if newtypes {
+            type_.name = newtype;
+            type_.module = None;
+        } else {
+            type_.name = "String".to_string();
+            type_.module = Some("PRIM".to_string());
+        }
+    }
+
+    for param in type_.parameters_mut() {
+        apply_policy(param, newtypes);
+    }
+}
+
+/// The imports needed by `local_declarations`, in the format of `PursModule`'s imports.
+pub const LOCAL_DECLARATION_IMPORTS: &[(&str, &[&str])] =
+    &[("Prelude", &[]), ("Data.Newtype", &["class Newtype"])];
+
+/// The names of the identifier newtypes in the types, each once.
+fn newtype_names(types: &[PursType]) -> Vec<String> {
+    fn collect(type_: &PursConstructor, names: &mut Vec<String>) {
+        if let Some(Marker::StringNewtype(name)) = type_.marker() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        for param in type_.parameters() {
+            collect(param, names);
+        }
+    }
+
+    let mut names = Vec::new();
+    for type_ in types {
+        for field_type in type_.field_types() {
+            collect(field_type, &mut names);
+        }
+    }
+    names.sort();
+    names
+}
+
+/// The declarations of the identifier newtypes used by the types, before the module renders them,
+/// with their instances. They derive the `String` instances, so codecs and generators can use
+/// them directly.
+pub fn local_declarations(types: &[PursType], codecs: bool, arbitrary: bool) -> Vec<String> {
+    newtype_names(types)
+        .into_iter()
+        .map(|name| {
+            let mut declaration = format!(
+                "newtype {name} = {name} String\n\nderive instance newtype{name} :: Newtype {name} _\nderive newtype instance eq{name} :: Eq {name}\nderive newtype instance ord{name} :: Ord {name}\n",
+                name = name
+            );
+            if codecs {
+                declaration.push_str(&format!(
+                    "derive newtype instance encodeJson{name} :: EncodeJson {name}\nderive newtype instance decodeJson{name} :: DecodeJson {name}\n",
+                    name = name
+                ));
+            }
+            if arbitrary {
+                declaration.push_str(&format!(
+                    "derive newtype instance arbitrary{name} :: Arbitrary {name}\n",
+                    name = name
+                ));
+            }
+            declaration
+        })
+        .collect()
+}
@@ -0,0 +1,38 @@
+//! Maps keeping the order of their entries, e.g. `indexmap::IndexMap`.
+
+use purs_constructor::*;
+use purs_type::*;
+
+/// The constructor for an ordered map from `key` to `value`. It is the `OrderedMap` synonym for an
+/// array of entries, declared in the module.
+#[allow(dead_code)] // Only used by implementations behind optional features.
+pub fn ordered_map(key: PursConstructor, value: PursConstructor) -> PursConstructor {
+    PursConstructor::new("OrderedMap", None, vec![key, value]).with_marker(Marker::OrderedMap)
+}
+
+/// Whether the type is the `OrderedMap` synonym declared in the module.
+pub fn is_ordered_map(type_: &PursConstructor) -> bool {
+    type_.marker() == Some(&Marker::OrderedMap)
+}
+
+/// The imports needed by `local_synonyms`, in the format of `PursModule`'s imports.
+pub const LOCAL_SYNONYM_IMPORTS: &[(&str, &[&str])] = &[("Data.Tuple", &["Tuple"])];
+
+/// The declaration of the `OrderedMap` synonym, if the module uses it.
+pub fn local_synonyms(types: &[PursType]) -> Vec<String> {
+    fn mentions(type_: &PursConstructor) -> bool {
+        is_ordered_map(type_) || type_.parameters().iter().any(mentions)
+    }
+
+    if types
+        .iter()
+        .any(|type_| type_.field_types().into_iter().any(mentions))
+    {
+        vec![
+            "-- | The entries of a map, in order.\ntype OrderedMap k v = Array (Tuple k v)\n"
+                .to_string(),
+        ]
+    } else {
+        Vec::new()
+    }
+}
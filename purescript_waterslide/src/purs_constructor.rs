@@ -6,28 +6,218 @@ pub trait AsPursConstructor {
     fn as_purs_constructor() -> PursConstructor;
 }
 
-/// Represents a Purescript type name with its parameters and which module it comes from.
-#[derive(Clone, Debug, Eq, PartialEq)]
+/// Represents a Purescript type: a type name with its parameters and which module it comes from,
+/// a type variable or a record type. Build it with `PursConstructor::new`, `variable` or `record`.
+///
+/// Constructors are equal when they stand for the same Purescript type, whatever Rust type they
+/// come from.
+#[derive(Clone, Debug)]
 pub struct PursConstructor {
     /// The Purescript module this type comes from. For example for `Option<T>` this is
     /// `Some("Data.Maybe".to_string())`.
     pub module: Option<String>,
     /// The Purescript name of this type. For `Option<T>` this would be "Maybe".
     pub name: String,
+    parameters: Vec<PursConstructor>,
+    kind: Kind,
+    marker: Option<Marker>,
+}
+
+/// What a `PursConstructor` stands for, which its name and module can't tell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    /// A type constructor, applied to its parameters if it has any.
+    Constructor,
+    /// A type variable, which has no parameters.
+    Variable,
+    /// A record type with these labels and JSON names, in the order of the types of its fields.
+    Record(Vec<(String, String)>),
+}
+
+/// Types whose representation or codecs depend on the module rendering them. They are displayed
+/// with a default name and module until the module applies its policies.
+#[allow(dead_code)] // Some are only used by implementations behind optional features.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Marker {
+    /// An integer wider than an `Int`, following the `WideIntegers` policy.
+    WideInteger,
+    /// An arbitrary-precision unsigned integer serialized as its digits, following the
+    /// `BigIntegers` policy.
+    BigUint,
+    /// An arbitrary-precision signed integer serialized as its sign and digits, following the
+    /// `BigIntegers` policy.
+    BigInt,
+    /// A base64 string, whose generated decoder validates the encoding.
+    Base64,
+    /// A date, following the `Dates` policy.
+    Date,
+    /// A time of day.
+    Time,
+    /// A date and time with an offset, normalized to UTC when decoded.
+    DateTime,
+    /// A date and time without an offset.
+    NaiveDateTime,
+    /// An arbitrary JSON value, following the `JsonValues` policy.
+    Json,
+    /// A decimal serialized as a string, following the `Decimals` policy.
+    Decimal,
+    /// An identifier serialized as a string, with the name of its newtype.
+    StringNewtype(String),
+    /// The `OrderedMap` synonym declared in the module.
+    OrderedMap,
+    /// The either crate's `Either`, imported from `Data.Either` but encoded with `Left` and
+    /// `Right` tags rather than `Err` and `Ok`.
+    Either,
+}
+
+impl PursConstructor {
+    /// A type constructor applied to the given parameters, e.g. `Maybe Int` or `String`.
+    pub fn new(name: &str, module: Option<&str>, parameters: Vec<PursConstructor>) -> Self {
+        PursConstructor {
+            module: module.map(str::to_string),
+            name: name.to_string(),
+            parameters,
+            kind: Kind::Constructor,
+            marker: None,
+        }
+    }
+
+    /// A type variable, e.g. the `t` in `Paginated t`. Unlike a constructor with the same name, it
+    /// is never imported.
+    pub fn variable(name: &str) -> Self {
+        PursConstructor {
+            module: None,
+            name: name.to_string(),
+            parameters: vec![],
+            kind: Kind::Variable,
+            marker: None,
+        }
+    }
+
+    /// A record type with the given labels and field types, e.g. `{ secs :: Number, nanos :: Int
+    /// }`.
+    pub fn record(fields: Vec<(String, PursConstructor)>) -> Self {
+        PursConstructor::json_record(
+            fields
+                .into_iter()
+                .map(|(label, type_)| (label.clone(), label, type_))
+                .collect(),
+        )
+    }
+
+    /// A record type whose fields have the given labels, JSON names and types, like a record
+    /// declared as a type synonym once inlined.
+    pub(crate) fn json_record(fields: Vec<(String, String, PursConstructor)>) -> Self {
+        let mut names = Vec::new();
+        let mut parameters = Vec::new();
+        for (label, json_name, type_) in fields {
+            names.push((label, json_name));
+            parameters.push(type_);
+        }
+        PursConstructor {
+            module: None,
+            name: "Record".to_string(),
+            parameters,
+            kind: Kind::Record(names),
+            marker: None,
+        }
+    }
+
+    /// Marks the constructor as a type the module renders according to its policies.
+    pub(crate) fn with_marker(mut self, marker: Marker) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    /// The marker of a type the module renders according to its policies.
+    pub(crate) fn marker(&self) -> Option<&Marker> {
+        self.marker.as_ref()
+    }
+
     /// The parameters this type accepts. For `Option<i32>` this would be the PursConstructor for
-    /// i32.
-    pub parameters: Vec<PursConstructor>,
+    /// i32. For records, these are the types of the fields.
+    pub fn parameters(&self) -> &[PursConstructor] {
+        &self.parameters
+    }
+
+    /// The parameters this type accepts, mutably. They can be replaced but not added or removed,
+    /// so that records keep one label per field.
+    pub fn parameters_mut(&mut self) -> &mut [PursConstructor] {
+        &mut self.parameters
+    }
+
+    /// Whether this is a type variable rather than a type constructor.
+    pub fn is_variable(&self) -> bool {
+        self.kind == Kind::Variable
+    }
+
+    /// Whether this is a record type.
+    pub fn is_record(&self) -> bool {
+        matches!(self.kind, Kind::Record(_))
+    }
+
+    /// Whether this is a type constructor applied to arguments, which is parenthesized when it is
+    /// itself an argument.
+    pub fn is_application(&self) -> bool {
+        self.kind == Kind::Constructor && !self.parameters.is_empty()
+    }
+
+    /// The labels and types of the fields of a record type.
+    pub fn record_fields(&self) -> Vec<(&str, &PursConstructor)> {
+        self.json_record_fields()
+            .into_iter()
+            .map(|(label, _, type_)| (label, type_))
+            .collect()
+    }
+
+    /// The labels, JSON names and types of the fields of a record type.
+    pub(crate) fn json_record_fields(&self) -> Vec<(&str, &str, &PursConstructor)> {
+        match self.kind {
+            Kind::Record(ref names) => names
+                .iter()
+                .zip(self.parameters.iter())
+                .map(|((label, json_name), type_)| {
+                    (label.as_str(), json_name.as_str(), type_)
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+// Markers only tell the module how to render the type, so a `Number` standing for a `u64` is
+// still a `Number`.
+impl PartialEq for PursConstructor {
+    fn eq(&self, other: &Self) -> bool {
+        self.module == other.module
+            && self.name == other.name
+            && self.parameters == other.parameters
+            && self.kind == other.kind
+    }
 }
 
+impl Eq for PursConstructor {}
+
 impl Display for PursConstructor {
     fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        if self.is_record() {
+            let fields: Vec<String> = self.record_fields()
+                .into_iter()
+                .map(|(label, type_)| format!("{} :: {}", label, type_))
+                .collect();
+            if fields.is_empty() {
+                return write!(f, "{{}}");
+            }
+            return write!(f, "{{ {} }}", fields.join(", "));
+        }
+
         write!(f, "{}", &self.name)?;
 
         for parameter in &self.parameters {
-            if parameter.parameters.is_empty() {
-                write!(f, " {}", parameter)?;
-            } else {
+            if parameter.is_application() {
                 write!(f, " ({})", parameter)?;
+            } else {
+                write!(f, " {}", parameter)?;
             }
         }
 
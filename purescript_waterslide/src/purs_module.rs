@@ -1,4 +1,16 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use arbitrary;
+use big_integers::{self, BigIntegers};
+use codecs;
+use dates::{self, Dates};
+use decimals::{self, Decimals};
+use integers::{self, WideIntegers};
+use json::{self, JsonValues};
+use lenses;
+use newtypes;
+use ordered_maps;
+use synonyms;
+use tuples;
 use purs_constructor::*;
 use purs_type::*;
 use std::fmt::{Display, Formatter};
@@ -11,12 +23,230 @@ pub struct PursModule {
     name: String,
     imports: BTreeMap<String, Vec<String>>,
     types: Vec<PursType>,
+    codecs: bool,
+    lenses: bool,
+    arbitrary: bool,
+    wide_integers: WideIntegers,
+    big_integers: BigIntegers,
+    dates: Dates,
+    json_values: JsonValues,
+    decimals: Decimals,
+    string_newtypes: bool,
 }
 
 impl PursModule {
     /// The `purs_module!` macro is slightly more convenient because it calls `as_purs_type` for
     /// you.
     pub fn new(name: String, types: Vec<PursType>) -> Self {
+        let mut module = PursModule {
+            name,
+            imports: BTreeMap::new(),
+            types,
+            codecs: false,
+            lenses: false,
+            arbitrary: false,
+            wide_integers: WideIntegers::default(),
+            big_integers: BigIntegers::default(),
+            dates: Dates::default(),
+            json_values: JsonValues::default(),
+            decimals: Decimals::default(),
+            string_newtypes: false,
+        };
+        module.refresh_imports();
+        module
+    }
+
+    /// Generate Argonaut `EncodeJson` and `DecodeJson` instances for every type in the module.
+    /// They follow `serde_json`'s default representation, so they can be used instead of the
+    /// generic Aeson codec without annotating enums on the Rust side. Enums with discriminants
+    /// are encoded as numbers, like `serde_repr` does. Maps with non-string keys are encoded as
+    /// JSON objects with stringified keys and `Result`s as `{"Ok": ...}` or `{"Err": ...}`, and
+    /// base64 byte buffers are validated, with helper functions added to the module.
+    pub fn with_codecs(mut self) -> Self {
+        self.codecs = true;
+        self.refresh_imports();
+        self
+    }
+
+    /// Convert the record labels of every type in the module to the given case, unless the type
+    /// sets its own with `#[purs(label_case = "...")]`. Generated codecs keep reading and writing
+    /// the JSON field names.
+    pub fn with_label_case(mut self, case: LabelCase) -> Self {
+        for type_ in &mut self.types {
+            let attributes = type_.attributes_mut();
+            if attributes.label_case.is_none() {
+                attributes.label_case = Some(case);
+            }
+        }
+        self
+    }
+
+    /// Declare records as `data`, `newtype` or type synonyms, unless the type sets its own
+    /// representation with `#[purs(representation = "...")]`.
+    pub fn with_record_representation(mut self, representation: RecordRepresentation) -> Self {
+        for type_ in &mut self.types {
+            if let PursType::Struct(_, _, ref mut attributes) = *type_ {
+                if attributes.representation.is_none() {
+                    attributes.representation = Some(representation);
+                }
+            }
+        }
+        self.refresh_imports();
+        self
+    }
+
+    /// Render `HashSet`s and `BTreeSet`s as `Array`s rather than `Data.Set.Set`s, for projects
+    /// that don't depend on `purescript-ordered-collections`. Both are JSON arrays.
+    pub fn with_sets_as_arrays(mut self) -> Self {
+        fn replace_sets(type_: &mut PursConstructor) {
+            if type_.name == "Set" && type_.module.as_deref() == Some("Data.Set") {
+                type_.name = "Array".to_string();
+                type_.module = None;
+            }
+            for param in type_.parameters_mut() {
+                replace_sets(param);
+            }
+        }
+
+        for type_ in &mut self.types {
+            for field_type in type_.field_types_mut() {
+                replace_sets(field_type);
+            }
+        }
+        self.refresh_imports();
+        self
+    }
+
+    /// Render tuples of three or more elements with the `TN` synonyms from `Data.Tuple.Nested`,
+    /// e.g. `T3 a b c` for `Tuple a (Tuple b c)`, the type built by `a /\ b /\ c`, rather than
+    /// with the `TupleN` synonyms terminated by `Unit`. Both are JSON arrays.
+    pub fn with_nested_tuples(mut self) -> Self {
+        for type_ in &mut self.types {
+            for field_type in type_.field_types_mut() {
+                tuples::nest(field_type);
+            }
+        }
+        self.refresh_imports();
+        self
+    }
+
+    /// Represent 64-bit and 128-bit integers, `isize` and `usize` as `Number`s (the default),
+    /// `BigInt`s or `String`s.
+    pub fn with_wide_integers(mut self, policy: WideIntegers) -> Self {
+        self.wide_integers = policy;
+        self.refresh_imports();
+        self
+    }
+
+    /// Represent arbitrary-precision integers serialized as their digits, e.g. `num_bigint::BigInt`s,
+    /// as their digits (the default) or as `BigInt`s.
+    pub fn with_big_integers(mut self, policy: BigIntegers) -> Self {
+        self.big_integers = policy;
+        self.refresh_imports();
+        self
+    }
+
+    /// Represent dates and times as `String`s (the default) or as `purescript-datetime` types.
+    pub fn with_dates(mut self, policy: Dates) -> Self {
+        self.dates = policy;
+        self.refresh_imports();
+        self
+    }
+
+    /// Represent arbitrary JSON values as Argonaut's `Json` (the default) or as `Foreign`s.
+    pub fn with_json_values(mut self, policy: JsonValues) -> Self {
+        self.json_values = policy;
+        self.refresh_imports();
+        self
+    }
+
+    /// Represent arbitrary-precision decimals serialized as strings, e.g. `rust_decimal::Decimal`s
+    /// and `bigdecimal::BigDecimal`s, as `String`s (the default) or as `Decimal`s.
+    pub fn with_decimals(mut self, policy: Decimals) -> Self {
+        self.decimals = policy;
+        self.refresh_imports();
+        self
+    }
+
+    /// Render identifiers serialized as strings, such as `url::Url`s and `semver::Version`s, as
+    /// newtypes around `String` declared in the module, e.g. `newtype Url = Url String`, rather
+    /// than as bare `String`s.
+    pub fn with_string_newtypes(mut self) -> Self {
+        self.string_newtypes = true;
+        self.refresh_imports();
+        self
+    }
+
+    /// Generate `purescript-profunctor-lenses` optics for the types in the module: an `Iso'` for
+    /// each record and newtype, a `Prism'` for each enum constructor, and a polymorphic `Lens`
+    /// for each record field name. Fields with the same name in several records share one lens.
+    ///
+    /// Optics are named after the types, constructors and fields with an underscore prefix. When a
+    /// name is taken by an earlier optic, prisms are qualified with the name of their type (e.g.
+    /// `_ActorUser` for the constructor `User` of `Actor` when the record `User` has the `_User`
+    /// iso), field lenses with `field` (e.g. `_fieldUser`), and other optics are followed by
+    /// primes.
+    pub fn with_lenses(mut self) -> Self {
+        self.lenses = true;
+        self.refresh_imports();
+        self
+    }
+
+    /// Generate `purescript-quickcheck` `Arbitrary` instances for the types in the module, for
+    /// example to check that values survive a round trip through the JSON codecs.
+    pub fn with_arbitrary_instances(mut self) -> Self {
+        self.arbitrary = true;
+        self.refresh_imports();
+        self
+    }
+
+    /// The types as they are rendered, with the module's representation policies applied. Types
+    /// declared in hand-written modules are left out.
+    fn rendered_types(&self) -> Vec<PursType> {
+        let mut types: Vec<PursType> = self.types
+            .iter()
+            .filter(|type_| !type_.is_declared_elsewhere())
+            .cloned()
+            .collect();
+        for type_ in &mut types {
+            for field_type in type_.field_types_mut() {
+                integers::apply_policy(field_type, self.wide_integers);
+                big_integers::apply_policy(field_type, self.big_integers);
+                dates::apply_policy(field_type, self.dates);
+                json::apply_policy(field_type, self.json_values);
+                decimals::apply_policy(field_type, self.decimals);
+                newtypes::apply_policy(field_type, self.string_newtypes);
+            }
+        }
+        types
+    }
+
+    /// The rendered types with the references to type synonyms replaced by their definitions, for
+    /// the generated code that depends on the structure of types.
+    fn expanded_types(&self) -> Vec<PursType> {
+        let mut types = self.rendered_types();
+        let synonyms = types.clone();
+        for type_ in &mut types {
+            for field_type in type_.field_types_mut() {
+                synonyms::expand(field_type, &synonyms);
+            }
+        }
+        types
+    }
+
+    /// The declarations of the identifier newtypes, if the module renders them.
+    fn string_newtype_declarations(&self) -> Vec<String> {
+        if self.string_newtypes {
+            newtypes::local_declarations(&self.types, self.codecs, self.arbitrary)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Recomputes the imports from the types and the generated code, since options can change
+    /// both.
+    fn refresh_imports(&mut self) {
+        let types = self.expanded_types();
         let mut imports = BTreeMap::new();
         imports.insert(
             "Data.Generic".to_string(),
@@ -24,49 +254,107 @@ impl PursModule {
         );
 
         for type_ in &types {
-            match *type_ {
-                PursType::Struct(ref name, ref fields) => {
-                    Self::accumulate_imports(&mut imports, name);
-
-                    for &(ref _name, ref type_) in fields.iter() {
-                        Self::accumulate_imports(&mut imports, type_)
-                    }
-                }
-                PursType::TupleStruct(ref name, ref fields) => {
-                    Self::accumulate_imports(&mut imports, name);
+            Self::accumulate_imports(&mut imports, type_.constructor());
+            for field_type in type_.field_types() {
+                Self::accumulate_imports(&mut imports, field_type);
+            }
+            Self::accumulate_declaration_imports(&mut imports, type_);
+        }
+        self.imports = imports;
+        if !tuples::local_synonyms(&types).is_empty() {
+            self.add_imports(tuples::LOCAL_SYNONYM_IMPORTS);
+        }
+        if !dates::local_synonyms(&types).is_empty() {
+            self.add_imports(dates::LOCAL_SYNONYM_IMPORTS);
+        }
+        if !ordered_maps::local_synonyms(&types).is_empty() {
+            self.add_imports(ordered_maps::LOCAL_SYNONYM_IMPORTS);
+        }
+        if !self.string_newtype_declarations().is_empty() {
+            self.add_imports(newtypes::LOCAL_DECLARATION_IMPORTS);
+        }
 
-                    for field in fields.iter() {
-                        Self::accumulate_imports(&mut imports, field)
-                    }
-                }
-                PursType::Enum(ref name, ref c) => {
-                    Self::accumulate_imports(&mut imports, name);
+        if self.codecs {
+            self.add_imports(codecs::CODEC_IMPORTS);
+            for helper in codecs::helpers(&types) {
+                self.add_imports(helper.imports);
+            }
+            let combinator_imports = codecs::combinator_imports(&types);
+            self.add_imports(&combinator_imports);
+            if types.iter().any(PursType::is_opaque) {
+                self.add_imports(codecs::OPAQUE_IMPORTS);
+            }
+        }
+        if self.lenses {
+            self.add_imports(lenses::LENS_IMPORTS);
+        }
+        if self.arbitrary {
+            self.add_imports(arbitrary::ARBITRARY_IMPORTS);
+            let generator_imports = arbitrary::generator_imports(&types);
+            self.add_imports(&generator_imports);
+            if types.iter().any(PursType::is_opaque) {
+                self.add_imports(arbitrary::OPAQUE_IMPORTS);
+            }
+        }
+    }
 
-                    for item in c.iter() {
-                        Self::accumulate_imports(&mut imports, item)
-                    }
-                }
+    /// Adds imports in the format of the constants of the generated code modules. An empty list
+    /// stands for an open import, which subsumes importing names from the module.
+    fn add_imports(&mut self, imports: &[(&str, &[&str])]) {
+        for &(module, names) in imports {
+            if names.is_empty() {
+                self.imports.insert(module.to_string(), Vec::new());
+            }
+            for name in names {
+                Self::add_import(&mut self.imports, module, name);
             }
         }
-        PursModule {
-            name,
-            imports,
-            types,
+    }
+
+    /// Imports needed by the declaration itself rather than by the types it mentions.
+    fn accumulate_declaration_imports(
+        imports: &mut BTreeMap<String, Vec<String>>,
+        type_: &PursType,
+    ) {
+        if codecs::is_int_enum(type_) {
+            Self::add_import(imports, "Data.Maybe", "Maybe(..)");
+        }
+
+        if type_.is_newtype() {
+            Self::add_import(imports, "Data.Newtype", "class Newtype");
         }
     }
 
     fn accumulate_imports(imports: &mut BTreeMap<String, Vec<String>>, type_: &PursConstructor) {
         if let Some(ref import) = type_.module {
-            let value = imports.entry(import.clone()).or_insert_with(Vec::new);
-            if value.iter().find(|i| **i == type_.name).is_none() {
-                value.push(type_.name.clone())
-            }
+            Self::add_import(imports, import, &type_.name);
         }
 
-        for param in &type_.parameters {
+        for param in type_.parameters() {
             Self::accumulate_imports(imports, param)
         }
     }
+
+    /// Adds a name to a module's import list. Importing a type with its constructors (`Maybe(..)`)
+    /// subsumes importing the type alone.
+    fn add_import(imports: &mut BTreeMap<String, Vec<String>>, module: &str, name: &str) {
+        if imports.get(module).is_some_and(Vec::is_empty) {
+            return;
+        }
+        let value = imports.entry(module.to_string()).or_insert_with(Vec::new);
+        let with_constructors = format!("{}(..)", name);
+        if value
+            .iter()
+            .any(|i| *i == name || *i == with_constructors)
+        {
+            return;
+        }
+
+        if let Some(type_name) = name.strip_suffix("(..)") {
+            value.retain(|i| i != type_name);
+        }
+        value.push(name.to_string())
+    }
 }
 
 impl Display for PursModule {
@@ -74,41 +362,82 @@ impl Display for PursModule {
         write!(f, "module {} where\n\n", self.name)?;
 
         for (key, value) in &self.imports {
-            if key == "PRIM" {
-                continue;
+            if value.is_empty() {
+                writeln!(f, "import {}", key)?;
             }
-            write!(f, "import {} (", key)?;
-            for v in value.iter() {
-                write!(f, "\n{}", v)?;
+        }
+
+        for (key, value) in &self.imports {
+            if key == "PRIM" || value.is_empty() {
+                continue;
             }
-            write!(f, "\n)\n")?;
+            write!(f, "import {} (\n  {}\n)\n", key, value.join(",\n  "))?;
         }
         write!(f, "\n")?;
 
-        let types = &self.types;
-        let output: Vec<String> = types
-            .into_iter()
-            .map(|type_| match *type_ {
-                PursType::TupleStruct(ref constructor, ref _fields) => format!(
-                    "{}\n\nderive instance generic{} :: Generic {}\n",
-                    type_,
-                    constructor.name,
-                    constructor.name
-                ),
-                PursType::Struct(ref constructor, ref _fields) => format!(
-                    "{}\n\nderive instance generic{} :: Generic {}\n",
-                    type_,
-                    constructor.name,
-                    constructor.name
-                ),
-                PursType::Enum(ref constructor, ref _constructors) => format!(
-                    "{}\n\nderive instance generic{} :: Generic {}\n",
-                    type_,
-                    constructor.name,
-                    constructor.name
-                ),
+        let declared = &self.rendered_types();
+        let types = &self.expanded_types();
+        let mut optics = BTreeSet::new();
+        let mut output: Vec<String> = declared
+            .iter()
+            .zip(types.iter())
+            .map(|(declared, type_)| {
+                let constructor = type_.constructor();
+                let mut declaration = format!("{}\n", declared);
+
+                // Type synonyms cannot have instances, and foreign types cannot derive them.
+                if !type_.is_type_synonym() && !type_.is_opaque() {
+                    declaration.push_str(&format!(
+                        "\nderive instance generic{} :: Generic {}\n",
+                        constructor.name,
+                        constructor.name
+                    ));
+                }
+
+                if type_.is_newtype() {
+                    declaration.push_str(&format!(
+                        "\nderive instance newtype{} :: Newtype {} _\n",
+                        constructor.name,
+                        codecs::instance_head(constructor)
+                    ));
+                }
+
+                if let Some(functions) = codecs::int_enum_functions(type_) {
+                    declaration.push_str(&format!("\n{}", functions));
+                }
+
+                if self.codecs && !type_.is_type_synonym() {
+                    declaration.push_str(&format!(
+                        "\n{}\n{}",
+                        codecs::encode_json_instance(type_),
+                        codecs::decode_json_instance(type_)
+                    ));
+                }
+
+                if self.arbitrary && !type_.is_type_synonym() {
+                    declaration.push_str(&format!("\n{}", arbitrary::arbitrary_instance(type_, types)));
+                }
+
+                if self.lenses {
+                    for optic in lenses::type_optics(declared, &mut optics) {
+                        declaration.push_str(&format!("\n{}", optic));
+                    }
+                }
+
+                declaration
             })
             .collect();
+
+        output.extend(tuples::local_synonyms(types));
+        output.extend(dates::local_synonyms(types));
+        output.extend(ordered_maps::local_synonyms(types));
+        output.extend(self.string_newtype_declarations());
+        if self.codecs {
+            output.extend(codecs::helpers(types).into_iter().map(|h| h.functions));
+        }
+        if self.lenses {
+            output.extend(lenses::field_lenses(declared, &mut optics));
+        }
         write!(f, "{}", output.join("\n"))?;
         Ok(())
     }
@@ -122,6 +451,12 @@ impl Display for PursModule {
 ///
 /// Note the usage of the `Void` type from the `void` crate as a type argument. Since the type
 /// arguments are not used when deriving `AsPursType`, any other type should work here.
+///
+/// Rust type aliases to declare as type synonyms can follow a second semicolon, as they would be
+/// passed to `purs_alias!`:
+///
+/// `purs_module!("Data.Pasta.Ingredients".to_string() ; TomatoSauce, OliveOil ; Pantry,
+/// Recipe<T>);`
 #[macro_export]
 macro_rules! purs_module {
     ( $name:expr ; $( $p:path ),* ) => {
@@ -131,5 +466,14 @@ macro_rules! purs_module {
             ];
             PursModule::new($name, purs_types)
         }
-    }
+    };
+    ( $name:expr ; $( $p:path ),* ; $( $alias:ident $( < $( $param:ident ),* > )* ),* ) => {
+        {
+            let mut purs_types = vec![
+                $( <$p>::as_purs_type() ),*
+            ];
+            $( purs_types.push($crate::purs_alias!($alias $( < $( $param ),* > )*)); )*
+            PursModule::new($name, purs_types)
+        }
+    };
 }
@@ -6,29 +6,334 @@ use purs_constructor::*;
 #[derive(Clone, Debug, PartialEq)]
 pub enum PursType {
     /// A purescript record
-    Struct(PursConstructor, Vec<(String, PursConstructor)>),
+    Struct(PursConstructor, Vec<PursField>, PursAttributes),
     /// A purescript type constructor with arguments
-    TupleStruct(PursConstructor, Vec<PursConstructor>),
+    TupleStruct(PursConstructor, Vec<PursConstructor>, PursAttributes),
     /// A purescript data type with multiple constructors
-    Enum(PursConstructor, Vec<PursConstructor>),
+    Enum(PursConstructor, Vec<PursVariant>, PursAttributes),
+    /// An abstract type, whose values can only be passed around. It is declared as
+    /// `foreign import data` when its constructor has no module, and otherwise belongs to that
+    /// hand-written module: generated modules then import it rather than declare it.
+    Opaque(PursConstructor, PursAttributes),
+    /// A type synonym for the second type, usually registered with `purs_alias!`. References to it
+    /// have no module, so it is declared in the module of the types using it.
+    Synonym(PursConstructor, PursConstructor, PursAttributes),
+}
+
+/// A field of a Purescript record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PursField {
+    /// The name of the field in JSON, taking serde's `rename` attributes into account.
+    pub name: String,
+    /// The type of the field.
+    pub type_: PursConstructor,
+    /// The documentation of the field, from its Rust doc comments.
+    pub doc: Option<String>,
+}
+
+/// A constructor of a Purescript data type derived from an enum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PursVariant {
+    /// The name of the variant in JSON, taking serde's `rename` attributes into account.
+    pub name: String,
+    /// The constructor's name and arguments.
+    pub constructor: PursConstructor,
+    /// The explicit discriminant value when the Rust enum is integer-encoded (e.g. with
+    /// `serde_repr`).
+    pub discriminant: Option<i64>,
+    /// The documentation of the constructor, from the Rust variant's doc comments.
+    pub doc: Option<String>,
+}
+
+impl PursField {
+    /// The record label for this field, which is its JSON name converted to the given case. It is
+    /// quoted when it isn't a Purescript identifier, e.g. `"page-num"` or `"@type"`.
+    pub fn label(&self, case: Option<LabelCase>) -> String {
+        quote_label(&self.label_name(case))
+    }
+
+    /// The name of the record label for this field, unquoted.
+    pub(crate) fn label_name(&self, case: Option<LabelCase>) -> String {
+        match case {
+            Some(case) => case.convert(&self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// A record label with the given name, quoted unless it is an identifier. Keywords such as `type`
+/// are valid labels.
+pub(crate) fn quote_label(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_lowercase() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '\'');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
+/// Information about a declaration that comes from the attributes of the Rust type.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PursAttributes {
+    /// The documentation of the type, from its Rust doc comments and deprecation notice.
+    pub doc: Option<String>,
+    /// How record labels are derived from field names. This can be set with
+    /// `#[purs(label_case = "camelCase")]` on the type, or for a whole module with
+    /// `PursModule::with_label_case`.
+    pub label_case: Option<LabelCase>,
+    /// How a record type is declared. This can be set with
+    /// `#[purs(representation = "newtype")]` on the type, or for a whole module with
+    /// `PursModule::with_record_representation`.
+    pub representation: Option<RecordRepresentation>,
+}
+
+/// The kind of declaration used for records.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordRepresentation {
+    /// `data Foo = Foo { ... }`, the default.
+    Data,
+    /// `newtype Foo = Foo { ... }`, with a derived `Newtype` instance. Tuple structs with a single
+    /// field are declared as `newtype Foo = Foo Bar`.
+    Newtype,
+    /// `type Foo = { ... }`. No instances are generated for type synonyms.
+    TypeSynonym,
+}
+
+/// The case of record labels. Generated codecs still use the JSON field names.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LabelCase {
+    /// Labels are the JSON field names.
+    Preserve,
+    /// Labels are converted to `camelCase`, e.g. `page_num` becomes `pageNum`.
+    CamelCase,
+    /// Labels are converted to `snake_case`, e.g. `pageNum` becomes `page_num`.
+    SnakeCase,
+}
+
+impl LabelCase {
+    /// Converts a field name to this case.
+    pub fn convert(&self, name: &str) -> String {
+        let words = split_words(name);
+        match *self {
+            LabelCase::Preserve => name.to_string(),
+            LabelCase::CamelCase => {
+                let mut label = String::new();
+                for (idx, word) in words.iter().enumerate() {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        if idx == 0 {
+                            label.extend(first.to_lowercase());
+                        } else {
+                            label.extend(first.to_uppercase());
+                        }
+                        label.extend(chars.flat_map(char::to_lowercase));
+                    }
+                }
+                label
+            }
+            LabelCase::SnakeCase => {
+                let words: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+                words.join("_")
+            }
+        }
+    }
+}
+
+/// Splits an identifier in any case into its words.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in name.chars() {
+        if c == '_' || c == '-' {
+            if !current.is_empty() {
+                words.push(current.clone());
+                current.clear();
+            }
+        } else {
+            let starts_word = c.is_uppercase()
+                && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric());
+            if starts_word && !current.is_empty() {
+                words.push(current.clone());
+                current.clear();
+            }
+            current.push(c);
+        }
+        previous = Some(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+impl PursType {
+    /// The constructor of the declared type itself, with its type parameters.
+    pub fn constructor(&self) -> &PursConstructor {
+        match *self {
+            PursType::Struct(ref type_, _, _)
+            | PursType::TupleStruct(ref type_, _, _)
+            | PursType::Enum(ref type_, _, _)
+            | PursType::Opaque(ref type_, _)
+            | PursType::Synonym(ref type_, _, _) => type_,
+        }
+    }
+
+    /// The attributes of the declaration.
+    pub fn attributes(&self) -> &PursAttributes {
+        match *self {
+            PursType::Struct(_, _, ref attributes)
+            | PursType::TupleStruct(_, _, ref attributes)
+            | PursType::Enum(_, _, ref attributes)
+            | PursType::Opaque(_, ref attributes)
+            | PursType::Synonym(_, _, ref attributes) => attributes,
+        }
+    }
+
+    /// Whether the type is declared as a type synonym, which cannot have instances.
+    pub fn is_type_synonym(&self) -> bool {
+        match *self {
+            PursType::Struct(_, _, ref attributes) => {
+                attributes.representation == Some(RecordRepresentation::TypeSynonym)
+            }
+            PursType::Synonym(_, _, _) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the type is declared as a `newtype`: a record, or a tuple struct with a single
+    /// field, with the `Newtype` representation.
+    pub fn is_newtype(&self) -> bool {
+        match *self {
+            PursType::Struct(_, _, ref attributes) => {
+                attributes.representation == Some(RecordRepresentation::Newtype)
+            }
+            PursType::TupleStruct(_, ref fields, ref attributes) => {
+                fields.len() == 1 && attributes.representation == Some(RecordRepresentation::Newtype)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the type is abstract, which rules out instances other than the JSON codecs and
+    /// `Arbitrary`.
+    pub fn is_opaque(&self) -> bool {
+        matches!(*self, PursType::Opaque(_, _))
+    }
+
+    /// Whether the type is declared in a hand-written module, so generated modules leave it out.
+    pub fn is_declared_elsewhere(&self) -> bool {
+        match *self {
+            PursType::Opaque(ref type_, _) => type_.module.is_some(),
+            _ => false,
+        }
+    }
+
+    /// The types of the record fields, tuple struct fields or enum constructor arguments, or the
+    /// definition of a type synonym.
+    pub fn field_types(&self) -> Vec<&PursConstructor> {
+        match *self {
+            PursType::Struct(_, ref fields, _) => fields.iter().map(|f| &f.type_).collect(),
+            PursType::TupleStruct(_, ref fields, _) => fields.iter().collect(),
+            PursType::Enum(_, ref constructors, _) => constructors
+                .iter()
+                .flat_map(|c| c.constructor.parameters().iter())
+                .collect(),
+            PursType::Opaque(_, _) => Vec::new(),
+            PursType::Synonym(_, ref target, _) => vec![target],
+        }
+    }
+
+    /// The types of the record fields, tuple struct fields or enum constructor arguments, or the
+    /// definition of a type synonym, mutably.
+    pub fn field_types_mut(&mut self) -> Vec<&mut PursConstructor> {
+        match *self {
+            PursType::Struct(_, ref mut fields, _) => {
+                fields.iter_mut().map(|f| &mut f.type_).collect()
+            }
+            PursType::TupleStruct(_, ref mut fields, _) => fields.iter_mut().collect(),
+            PursType::Enum(_, ref mut constructors, _) => constructors
+                .iter_mut()
+                .flat_map(|c| c.constructor.parameters_mut().iter_mut())
+                .collect(),
+            PursType::Opaque(_, _) => Vec::new(),
+            PursType::Synonym(_, ref mut target, _) => vec![target],
+        }
+    }
+
+    /// The attributes of the declaration, mutably.
+    pub fn attributes_mut(&mut self) -> &mut PursAttributes {
+        match *self {
+            PursType::Struct(_, _, ref mut attributes)
+            | PursType::TupleStruct(_, _, ref mut attributes)
+            | PursType::Enum(_, _, ref mut attributes)
+            | PursType::Opaque(_, ref mut attributes)
+            | PursType::Synonym(_, _, ref mut attributes) => attributes,
+        }
+    }
+}
+
+/// Writes documentation as Purescript doc comments, one per line, each followed by a newline and
+/// the given indentation.
+fn write_doc(f: &mut Formatter, doc: &str, indent: &str) -> ::std::fmt::Result {
+    for line in doc.lines() {
+        if line.is_empty() {
+            write!(f, "-- |\n{}", indent)?;
+        } else {
+            write!(f, "-- | {}\n{}", line, indent)?;
+        }
+    }
+    Ok(())
 }
 
 impl Display for PursType {
     fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
         use PursType::*;
 
+        if let Some(ref doc) = self.attributes().doc {
+            write_doc(f, doc, "")?;
+        }
+        let label_case = self.attributes().label_case;
+
         match *self {
-            Struct(ref type_, ref fields) => {
-                write!(f, "data {} ", type_.name)?;
+            Struct(ref type_, ref fields, ref attributes) => {
+                let (keyword, constructor) = match attributes.representation {
+                    None | Some(RecordRepresentation::Data) => ("data", Some(&type_.name)),
+                    Some(RecordRepresentation::Newtype) => ("newtype", Some(&type_.name)),
+                    Some(RecordRepresentation::TypeSynonym) => ("type", None),
+                };
+                write!(f, "{} {} ", keyword, type_.name)?;
 
-                for param in &type_.parameters {
+                for param in type_.parameters() {
                     write!(f, "{} ", &param.name)?;
                 }
 
-                write!(f, "= {} {{ ", type_.name)?;
+                write!(f, "=")?;
+                if let Some(constructor) = constructor {
+                    write!(f, " {}", constructor)?;
+                }
 
-                for (idx, &(ref name, ref constructor)) in fields.iter().enumerate() {
-                    write!(f, "{} :: {}", name, constructor)?;
+                if fields.iter().any(|field| field.doc.is_some()) {
+                    for (idx, field) in fields.iter().enumerate() {
+                        write!(f, "\n  {} ", if idx == 0 { "{" } else { "," })?;
+                        if let Some(ref doc) = field.doc {
+                            write_doc(f, doc, "    ")?;
+                        }
+                        write!(f, "{} :: {}", field.label(label_case), field.type_)?;
+                    }
+                    return write!(f, "\n  }}");
+                }
+
+                write!(f, " {{ ")?;
+
+                for (idx, field) in fields.iter().enumerate() {
+                    write!(f, "{} :: {}", field.label(label_case), field.type_)?;
                     if idx < (fields.len() - 1) {
                         write!(f, ",")?;
                     }
@@ -36,41 +341,77 @@ impl Display for PursType {
                 }
                 write!(f, "}}")
             }
-            TupleStruct(ref type_, ref fields) => {
-                write!(f, "data {} ", type_.name)?;
+            TupleStruct(ref type_, ref fields, _) => {
+                let keyword = if self.is_newtype() { "newtype" } else { "data" };
+                write!(f, "{} {} ", keyword, type_.name)?;
 
-                for param in &type_.parameters {
+                for param in type_.parameters() {
                     write!(f, "{} ", &param.name)?;
                 }
 
                 write!(f, "= {}", type_.name)?;
 
                 for field in fields.iter() {
-                    if field.parameters.is_empty() {
-                        write!(f, " {}", field)?;
-                    } else {
+                    if field.is_application() {
                         write!(f, " ({})", field)?;
+                    } else {
+                        write!(f, " {}", field)?;
                     }
                 }
                 Ok(())
             }
-            Enum(ref type_, ref constructors) => {
-                write!(f, "data {} ", type_.name)?;
+            Enum(ref type_, ref constructors, _) => {
+                write!(f, "data {}", type_.name)?;
 
-                for param in &type_.parameters {
-                    write!(f, "{} ", &param.name)?;
+                for param in type_.parameters() {
+                    write!(f, " {}", &param.name)?;
+                }
+
+                if constructors.iter().any(|variant| variant.doc.is_some()) {
+                    for (idx, variant) in constructors.iter().enumerate() {
+                        write!(f, "\n  ")?;
+                        if let Some(ref doc) = variant.doc {
+                            write_doc(f, doc, "  ")?;
+                        }
+                        write!(
+                            f,
+                            "{} {}",
+                            if idx == 0 { "=" } else { "|" },
+                            variant.constructor
+                        )?;
+                    }
+                    return Ok(());
                 }
 
-                write!(f, "= ")?;
+                // Types without constructors, like `Void`, are declared without any.
+                if constructors.is_empty() {
+                    return Ok(());
+                }
+
+                write!(f, " = ")?;
 
-                for (idx, constructor) in constructors.iter().enumerate() {
-                    write!(f, "{}", constructor)?;
+                for (idx, variant) in constructors.iter().enumerate() {
+                    write!(f, "{}", variant.constructor)?;
                     if idx < constructors.len() - 1 {
                         write!(f, " | ")?;
                     }
                 }
                 Ok(())
             }
+            Opaque(ref type_, _) => {
+                write!(f, "foreign import data {} ::", type_.name)?;
+                for _ in type_.parameters() {
+                    write!(f, " Type ->")?;
+                }
+                write!(f, " Type")
+            }
+            Synonym(ref type_, ref target, _) => {
+                write!(f, "type {}", type_.name)?;
+                for param in type_.parameters() {
+                    write!(f, " {}", &param.name)?;
+                }
+                write!(f, " = {}", target)
+            }
         }
     }
 }
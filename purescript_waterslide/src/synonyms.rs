@@ -0,0 +1,69 @@
+//! Type synonyms declared in a module, e.g. with `purs_alias!`, and the references to them.
+
+use purs_constructor::*;
+use purs_type::*;
+
+/// Replaces the references to the type synonyms among `types` with their definitions, for the
+/// generated code that depends on the structure of types. Records declared as type synonyms are
+/// replaced with record types keeping the JSON names of their fields.
+///
+/// Purescript rejects recursive type synonyms, such as a record declared as a type synonym with a
+/// field of its own type: the references to a synonym within its own definition are kept.
+pub fn expand(type_: &mut PursConstructor, types: &[PursType]) {
+    expand_nested(type_, types, &mut Vec::new());
+}
+
+/// Expands the type appearing in the definitions of the synonyms being expanded, whose references
+/// are kept.
+fn expand_nested(type_: &mut PursConstructor, types: &[PursType], expanding: &mut Vec<String>) {
+    for param in type_.parameters_mut() {
+        expand_nested(param, types, expanding);
+    }
+    if type_.module.is_some()
+        || type_.is_variable()
+        || type_.is_record()
+        || expanding.contains(&type_.name)
+    {
+        return;
+    }
+
+    let definition = types.iter().find_map(|declaration| {
+        let synonym = declaration.constructor();
+        if !declaration.is_type_synonym()
+            || synonym.name != type_.name
+            || synonym.parameters().len() != type_.parameters().len()
+        {
+            return None;
+        }
+        match *declaration {
+            PursType::Synonym(_, ref target, _) => Some((synonym, target.clone())),
+            PursType::Struct(_, ref fields, ref attributes) => {
+                let fields = fields
+                    .iter()
+                    .map(|f| (f.label(attributes.label_case), f.name.clone(), f.type_.clone()))
+                    .collect();
+                Some((synonym, PursConstructor::json_record(fields)))
+            }
+            _ => None,
+        }
+    });
+    if let Some((synonym, target)) = definition {
+        let mut expanded = target;
+        substitute(&mut expanded, synonym.parameters(), type_.parameters());
+        expanding.push(type_.name.clone());
+        expand_nested(&mut expanded, types, expanding);
+        expanding.pop();
+        *type_ = expanded;
+    }
+}
+
+/// Replaces the type variables of a synonym's definition with the arguments it is applied to.
+fn substitute(type_: &mut PursConstructor, variables: &[PursConstructor], values: &[PursConstructor]) {
+    if let Some(idx) = variables.iter().position(|variable| variable == type_) {
+        *type_ = values[idx].clone();
+        return;
+    }
+    for param in type_.parameters_mut() {
+        substitute(param, variables, values);
+    }
+}
@@ -0,0 +1,15 @@
+extern crate arrayvec;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl<T: AsPursConstructor, const CAP: usize> AsPursConstructor for arrayvec::ArrayVec<T, CAP> {
+    fn as_purs_constructor() -> PursConstructor {
+        <Vec<T> as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl<const CAP: usize> AsPursConstructor for arrayvec::ArrayString<CAP> {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
@@ -0,0 +1,54 @@
+extern crate base64;
+extern crate serde;
+
+use std::fmt;
+use std::marker::PhantomData;
+use self::base64::Engine;
+use self::base64::engine::general_purpose::STANDARD;
+use self::serde::de::{self, Deserialize, Deserializer, Visitor};
+use self::serde::ser::{Serialize, Serializer};
+use bytes::{ArrayBufferBytes, Base64Bytes};
+
+struct Base64Visitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for Base64Visitor<T>
+where
+    T: From<Vec<u8>>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a base64 string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        STANDARD.decode(value).map(T::from).map_err(E::custom)
+    }
+}
+
+macro_rules! base64_serde_impls {
+    ($wrapper:ident) => {
+        impl<T> Serialize for $wrapper<T>
+        where
+            T: AsRef<[u8]>,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&STANDARD.encode(self.0.as_ref()))
+            }
+        }
+
+        impl<'de, T> Deserialize<'de> for $wrapper<T>
+        where
+            T: From<Vec<u8>>,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer
+                    .deserialize_str(Base64Visitor(PhantomData))
+                    .map($wrapper)
+            }
+        }
+    }
+}
+
+base64_serde_impls!(Base64Bytes);
+base64_serde_impls!(ArrayBufferBytes);
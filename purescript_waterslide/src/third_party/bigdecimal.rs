@@ -0,0 +1,10 @@
+extern crate bigdecimal;
+
+use decimals::decimal;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for bigdecimal::BigDecimal {
+    fn as_purs_constructor() -> PursConstructor {
+        decimal()
+    }
+}
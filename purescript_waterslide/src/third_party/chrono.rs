@@ -1,59 +1,41 @@
 extern crate chrono;
 
-use purs_constructor::{AsPursConstructor, PursConstructor};
+use dates::date_constructor;
+use purs_constructor::{AsPursConstructor, Marker, PursConstructor};
 
 impl<T> AsPursConstructor for chrono::DateTime<T>
 where
     T: chrono::TimeZone,
 {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        date_constructor(Marker::DateTime)
     }
 }
 
+#[allow(deprecated)]
 impl<T> AsPursConstructor for chrono::Date<T>
 where
     T: chrono::TimeZone,
 {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        <String as AsPursConstructor>::as_purs_constructor()
     }
 }
 
 impl AsPursConstructor for chrono::naive::NaiveDate {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        date_constructor(Marker::Date)
     }
 }
 
 impl AsPursConstructor for chrono::naive::NaiveTime {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        date_constructor(Marker::Time)
     }
 }
 
 impl AsPursConstructor for chrono::naive::NaiveDateTime {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        date_constructor(Marker::NaiveDateTime)
     }
 }
@@ -0,0 +1,17 @@
+extern crate either;
+
+use purs_constructor::{AsPursConstructor, Marker, PursConstructor};
+
+impl<L, R> AsPursConstructor for either::Either<L, R>
+where
+    L: AsPursConstructor,
+    R: AsPursConstructor,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "Either",
+            Some("Data.Either"),
+            vec![L::as_purs_constructor(), R::as_purs_constructor()],
+        ).with_marker(Marker::Either)
+    }
+}
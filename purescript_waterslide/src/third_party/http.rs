@@ -0,0 +1,17 @@
+extern crate http;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// http has no serde support of its own. These follow the http-serde adapters, which serialize
+// status codes as numbers and methods as strings.
+impl AsPursConstructor for http::StatusCode {
+    fn as_purs_constructor() -> PursConstructor {
+        <u16 as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for http::Method {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
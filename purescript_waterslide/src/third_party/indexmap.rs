@@ -0,0 +1,21 @@
+extern crate indexmap;
+
+use ordered_maps::ordered_map;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// Index maps are serialized as JSON objects in insertion order, which generated codecs keep.
+impl<K, V, S> AsPursConstructor for indexmap::IndexMap<K, V, S>
+where
+    K: AsPursConstructor,
+    V: AsPursConstructor,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        ordered_map(K::as_purs_constructor(), V::as_purs_constructor())
+    }
+}
+
+impl<T: AsPursConstructor, S> AsPursConstructor for indexmap::IndexSet<T, S> {
+    fn as_purs_constructor() -> PursConstructor {
+        <Vec<T> as AsPursConstructor>::as_purs_constructor()
+    }
+}
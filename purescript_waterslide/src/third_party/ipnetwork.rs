@@ -0,0 +1,22 @@
+extern crate ipnetwork;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// Networks are serialized in CIDR notation, e.g. `"192.168.0.0/16"`.
+impl AsPursConstructor for ipnetwork::IpNetwork {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for ipnetwork::Ipv4Network {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for ipnetwork::Ipv6Network {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
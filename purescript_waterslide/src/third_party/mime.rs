@@ -0,0 +1,10 @@
+extern crate mime;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// mime has no serde support of its own. Adapters such as mime_serde_shim use the string form.
+impl AsPursConstructor for mime::Mime {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
@@ -1,4 +1,40 @@
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "base64_support")]
+mod base64;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork;
+#[cfg(feature = "mime")]
+mod mime;
+#[cfg(feature = "nonempty")]
+mod nonempty;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "semver")]
+mod semver;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "ulid")]
+mod ulid;
+#[cfg(feature = "url")]
+mod url;
 #[cfg(feature = "uuid")]
 mod uuid;
+#[cfg(feature = "uuid1")]
+mod uuid1;
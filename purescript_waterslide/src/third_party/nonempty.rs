@@ -0,0 +1,14 @@
+extern crate nonempty;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// Serialized as an array, and deserialized from a non-empty one.
+impl<T: AsPursConstructor> AsPursConstructor for nonempty::NonEmpty<T> {
+    fn as_purs_constructor() -> PursConstructor {
+        PursConstructor::new(
+            "NonEmptyArray",
+            Some("Data.Array.NonEmpty"),
+            vec![T::as_purs_constructor()],
+        )
+    }
+}
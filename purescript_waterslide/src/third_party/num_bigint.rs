@@ -0,0 +1,18 @@
+extern crate num_bigint;
+
+use big_integers::{big_signed, big_unsigned};
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// num-bigint serializes integers as their little-endian base 2^32 digits, which don't all fit in
+// an `Int`. Signed integers are preceded by their sign, -1, 0 or 1.
+impl AsPursConstructor for num_bigint::BigUint {
+    fn as_purs_constructor() -> PursConstructor {
+        big_unsigned()
+    }
+}
+
+impl AsPursConstructor for num_bigint::BigInt {
+    fn as_purs_constructor() -> PursConstructor {
+        big_signed()
+    }
+}
@@ -0,0 +1,21 @@
+extern crate rust_decimal;
+
+#[cfg(not(feature = "rust_decimal_float"))]
+use decimals::decimal;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+// `rust_decimal` serializes decimals as strings, unless its `serde-float` feature is enabled,
+// which the `rust_decimal_float` feature does.
+#[cfg(not(feature = "rust_decimal_float"))]
+impl AsPursConstructor for rust_decimal::Decimal {
+    fn as_purs_constructor() -> PursConstructor {
+        decimal()
+    }
+}
+
+#[cfg(feature = "rust_decimal_float")]
+impl AsPursConstructor for rust_decimal::Decimal {
+    fn as_purs_constructor() -> PursConstructor {
+        <f64 as AsPursConstructor>::as_purs_constructor()
+    }
+}
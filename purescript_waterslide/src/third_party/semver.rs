@@ -0,0 +1,16 @@
+extern crate semver;
+
+use newtypes::string_newtype;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for semver::Version {
+    fn as_purs_constructor() -> PursConstructor {
+        string_newtype("Version")
+    }
+}
+
+impl AsPursConstructor for semver::VersionReq {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
@@ -0,0 +1,23 @@
+extern crate serde_json;
+
+use json::json_value;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for serde_json::Value {
+    fn as_purs_constructor() -> PursConstructor {
+        json_value()
+    }
+}
+
+impl AsPursConstructor for serde_json::Map<String, serde_json::Value> {
+    fn as_purs_constructor() -> PursConstructor {
+        json_value()
+    }
+}
+
+// `RawValue` is unsized, so fields hold a `Box<RawValue>`.
+impl AsPursConstructor for serde_json::value::RawValue {
+    fn as_purs_constructor() -> PursConstructor {
+        json_value()
+    }
+}
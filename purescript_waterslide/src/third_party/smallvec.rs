@@ -0,0 +1,13 @@
+extern crate smallvec;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl<A> AsPursConstructor for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: AsPursConstructor,
+{
+    fn as_purs_constructor() -> PursConstructor {
+        <Vec<A::Item> as AsPursConstructor>::as_purs_constructor()
+    }
+}
@@ -0,0 +1,42 @@
+extern crate time;
+
+use dates::date_constructor;
+use purs_constructor::{AsPursConstructor, Marker, PursConstructor};
+
+// The `time_support` feature enables time's `serde-human-readable` feature, without which these
+// types serialize as arrays of integers rather than strings.
+
+// The default serde format of dates and times, e.g. `2024-01-01 12:00:00.0 +00:00:00`, isn't
+// RFC 3339, which generated date codecs expect. Fields with the `time::serde::rfc3339` adapters
+// are `Rfc3339DateTime`s and those with the `time::serde::timestamp` adapters are integers, which
+// the derive detects.
+impl AsPursConstructor for time::OffsetDateTime {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for time::PrimitiveDateTime {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
+
+impl AsPursConstructor for time::Date {
+    fn as_purs_constructor() -> PursConstructor {
+        date_constructor(Marker::Date)
+    }
+}
+
+impl AsPursConstructor for time::Time {
+    fn as_purs_constructor() -> PursConstructor {
+        date_constructor(Marker::Time)
+    }
+}
+
+// Human-readable formats serialize durations as `"seconds.nanoseconds"`.
+impl AsPursConstructor for time::Duration {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
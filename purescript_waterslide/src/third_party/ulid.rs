@@ -0,0 +1,9 @@
+extern crate ulid;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for ulid::Ulid {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
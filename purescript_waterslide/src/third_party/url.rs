@@ -0,0 +1,10 @@
+extern crate url;
+
+use newtypes::string_newtype;
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for url::Url {
+    fn as_purs_constructor() -> PursConstructor {
+        string_newtype("Url")
+    }
+}
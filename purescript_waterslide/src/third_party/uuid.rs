@@ -4,10 +4,6 @@ use purs_constructor::{AsPursConstructor, PursConstructor};
 
 impl AsPursConstructor for uuid::Uuid {
     fn as_purs_constructor() -> PursConstructor {
-        PursConstructor {
-            module: None,
-            name: "String".to_string(),
-            parameters: vec![],
-        }
+        <String as AsPursConstructor>::as_purs_constructor()
     }
 }
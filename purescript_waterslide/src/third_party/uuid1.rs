@@ -0,0 +1,9 @@
+extern crate uuid1;
+
+use purs_constructor::{AsPursConstructor, PursConstructor};
+
+impl AsPursConstructor for uuid1::Uuid {
+    fn as_purs_constructor() -> PursConstructor {
+        <String as AsPursConstructor>::as_purs_constructor()
+    }
+}
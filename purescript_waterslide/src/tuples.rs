@@ -0,0 +1,142 @@
+//! Tuples of three or more elements, represented with the type synonyms from
+//! `Data.Tuple.Nested`.
+
+use purs_constructor::*;
+use purs_type::*;
+
+/// Type variable names for the elements of a tuple.
+const ELEMENT_NAMES: &str = "abcdefghijkl";
+
+/// The module of the tuple synonyms.
+const NESTED: &str = "Data.Tuple.Nested";
+
+/// The largest arities of the `TupleN` and `TN` synonyms in `Data.Tuple.Nested`. Larger tuples
+/// use synonyms declared in the generated module.
+const MAX_TUPLE_SYNONYM: usize = 10;
+const MAX_T_SYNONYM: usize = 11;
+
+/// How the elements of a tuple are nested.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Nesting {
+    /// `TupleN a b c`, that is `Tuple a (Tuple b (Tuple c Unit))`.
+    UnitTerminated,
+    /// `Tuple a b`, or `TN a b c`, that is `Tuple a (Tuple b c)`.
+    Direct,
+}
+
+/// The names of the elements of a tuple of the given arity.
+pub fn element_names(arity: usize) -> Vec<String> {
+    ELEMENT_NAMES
+        .chars()
+        .take(arity)
+        .map(|c| c.to_string())
+        .collect()
+}
+
+/// The constructor for a Rust tuple of the given elements, with three to twelve elements.
+pub fn tuple_constructor(parameters: Vec<PursConstructor>) -> PursConstructor {
+    let arity = parameters.len();
+    let module = if arity <= MAX_TUPLE_SYNONYM {
+        Some(NESTED)
+    } else {
+        None
+    };
+    PursConstructor::new(&format!("Tuple{}", arity), module, parameters)
+}
+
+/// The arity and nesting of a tuple type.
+pub fn tuple_arity(type_: &PursConstructor) -> Option<(usize, Nesting)> {
+    let module = type_.module.as_deref();
+    if type_.name == "Tuple" && module == Some("Data.Tuple") && type_.parameters().len() == 2 {
+        return Some((2, Nesting::Direct));
+    }
+
+    let (arity, nesting, max) = if let Some(arity) = type_.name.strip_prefix("Tuple") {
+        (arity, Nesting::UnitTerminated, MAX_TUPLE_SYNONYM)
+    } else if let Some(arity) = type_.name.strip_prefix('T') {
+        (arity, Nesting::Direct, MAX_T_SYNONYM)
+    } else {
+        return None;
+    };
+    let arity: usize = arity.parse().ok()?;
+    let expected_module = if arity <= max { Some(NESTED) } else { None };
+
+    if arity >= 3 && arity == type_.parameters().len() && module == expected_module {
+        Some((arity, nesting))
+    } else {
+        None
+    }
+}
+
+/// Replaces the `TupleN` synonyms with the `TN` synonyms, which `/\` builds.
+pub fn nest(type_: &mut PursConstructor) {
+    if let Some((arity, Nesting::UnitTerminated)) = tuple_arity(type_) {
+        type_.name = format!("T{}", arity);
+        type_.module = if arity <= MAX_T_SYNONYM {
+            Some(NESTED.to_string())
+        } else {
+            None
+        };
+    }
+
+    for param in type_.parameters_mut() {
+        nest(param);
+    }
+}
+
+/// The nested `Tuple`s a synonym stands for, with the given elements.
+pub fn nested_tuples(elements: &[String], nesting: Nesting) -> String {
+    let (last, init) = match nesting {
+        Nesting::UnitTerminated => ("Unit".to_string(), elements),
+        Nesting::Direct => (elements[elements.len() - 1].clone(), &elements[..elements.len() - 1]),
+    };
+    init.iter().rev().fold(last, |nested, element| {
+        if nested.contains(' ') {
+            format!("Tuple {} ({})", element, nested)
+        } else {
+            format!("Tuple {} {}", element, nested)
+        }
+    })
+}
+
+/// The imports needed by `local_synonyms`, in the format of `PursModule`'s imports.
+pub const LOCAL_SYNONYM_IMPORTS: &[(&str, &[&str])] =
+    &[("Data.Tuple", &["Tuple"]), ("Prelude", &["Unit"])];
+
+/// Declarations of the synonyms for tuples too large for `Data.Tuple.Nested`.
+pub fn local_synonyms(types: &[PursType]) -> Vec<String> {
+    fn collect(type_: &PursConstructor, synonyms: &mut Vec<(usize, Nesting)>) {
+        if type_.module.is_none() {
+            if let Some(tuple) = tuple_arity(type_) {
+                if !synonyms.contains(&tuple) {
+                    synonyms.push(tuple);
+                }
+            }
+        }
+        for param in type_.parameters() {
+            collect(param, synonyms);
+        }
+    }
+
+    let mut synonyms = Vec::new();
+    for type_ in types {
+        for field_type in type_.field_types() {
+            collect(field_type, &mut synonyms);
+        }
+    }
+    synonyms.sort_by_key(|&(arity, nesting)| (nesting == Nesting::Direct, arity));
+
+    synonyms
+        .into_iter()
+        .map(|(arity, nesting)| {
+            let elements = element_names(arity);
+            format!(
+                "type {}{} {} = {}\n",
+                if nesting == Nesting::Direct { "T" } else { "Tuple" },
+                arity,
+                elements.join(" "),
+                nested_tuples(&elements, nesting)
+            )
+        })
+        .collect()
+}
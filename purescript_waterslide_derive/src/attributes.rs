@@ -0,0 +1,324 @@
+use syn::{Attribute, Lit, MetaItem, NestedMetaItem};
+use quote::{ToTokens, Tokens};
+
+/// The documentation of an item: its doc comments, followed by its deprecation notice if it has
+/// one.
+pub fn doc(attrs: &[Attribute]) -> Option<String> {
+    let mut lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match attr.value {
+            MetaItem::NameValue(ref ident, Lit::Str(ref doc, _)) if ident == "doc" => {
+                Some(doc_line(doc))
+            }
+            _ => None,
+        })
+        .collect();
+
+    if let Some(deprecation) = attrs.iter().filter_map(deprecation).next() {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(deprecation);
+    }
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Strips the comment markers from sugared doc comments, and the space following them.
+fn doc_line(doc: &str) -> String {
+    let line = doc
+        .strip_prefix("///")
+        .or_else(|| doc.strip_prefix("//!"))
+        .unwrap_or(doc);
+    let line = line.strip_prefix(' ').unwrap_or(line);
+    line.trim_end().to_string()
+}
+
+fn deprecation(attr: &Attribute) -> Option<String> {
+    match attr.value {
+        MetaItem::Word(ref ident) if ident == "deprecated" => Some("Deprecated".to_string()),
+        MetaItem::NameValue(ref ident, Lit::Str(ref note, _)) if ident == "deprecated" => {
+            Some(format!("Deprecated: {}", note))
+        }
+        MetaItem::List(ref ident, ref items) if ident == "deprecated" => {
+            let mut note = items.iter().filter_map(|item| match *item {
+                NestedMetaItem::MetaItem(MetaItem::NameValue(ref name, Lit::Str(ref note, _)))
+                    if name == "note" =>
+                {
+                    Some(note)
+                }
+                _ => None,
+            });
+            match note.next_back() {
+                Some(note) => Some(format!("Deprecated: {}", note)),
+                None => Some("Deprecated".to_string()),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The string value of `key` in `#[namespace(key = "value")]` attributes.
+pub fn name_value(attrs: &[Attribute], namespace: &str, key: &str) -> Option<String> {
+    nested_items(attrs, namespace)
+        .filter_map(|item| match *item {
+            NestedMetaItem::MetaItem(MetaItem::NameValue(ref name, Lit::Str(ref value, _)))
+                if name == key =>
+            {
+                Some(value.clone())
+            }
+            _ => None,
+        })
+        .last()
+}
+
+/// Whether `#[namespace(word)]` is set.
+pub fn word(attrs: &[Attribute], namespace: &str, word: &str) -> bool {
+    nested_items(attrs, namespace).any(|item| match *item {
+        NestedMetaItem::MetaItem(MetaItem::Word(ref ident)) => ident == word,
+        _ => false,
+    })
+}
+
+/// Whether serde leaves the field out of its output, with `#[serde(skip)]` or
+/// `#[serde(skip_serializing)]`.
+pub fn skipped(attrs: &[Attribute]) -> bool {
+    word(attrs, "serde", "skip") || word(attrs, "serde", "skip_serializing")
+}
+
+fn nested_items<'a>(
+    attrs: &'a [Attribute],
+    namespace: &'a str,
+) -> impl Iterator<Item = &'a NestedMetaItem> + 'a {
+    attrs.iter().flat_map(move |attr| match attr.value {
+        MetaItem::List(ref ident, ref items) if ident == namespace => items.iter(),
+        _ => [].iter(),
+    })
+}
+
+/// The name set with serde's `rename` attribute, or its `serialize` name.
+fn serde_rename(attrs: &[Attribute]) -> Option<String> {
+    let serialize_rename = nested_items(attrs, "serde")
+        .filter_map(|item| match *item {
+            NestedMetaItem::MetaItem(MetaItem::List(ref name, ref items)) if name == "rename" => {
+                Some(items)
+            }
+            _ => None,
+        })
+        .flat_map(|items| items.iter())
+        .filter_map(|item| match *item {
+            NestedMetaItem::MetaItem(MetaItem::NameValue(ref name, Lit::Str(ref value, _)))
+                if name == "serialize" =>
+            {
+                Some(value.clone())
+            }
+            _ => None,
+        })
+        .last();
+
+    name_value(attrs, "serde", "rename").or(serialize_rename)
+}
+
+/// The name serde serializes a field under: its `rename` attribute, else its Rust name with the
+/// container's `rename_all` rule applied.
+pub fn serde_name(field: &str, attrs: &[Attribute], rename_all: Option<&str>) -> String {
+    if let Some(name) = serde_rename(attrs) {
+        return name;
+    }
+
+    match rename_all {
+        Some(rule) => apply_rename_rule(rule, field),
+        None => field.to_string(),
+    }
+}
+
+/// The name serde serializes a variant under: its `rename` attribute, else its Rust name with the
+/// enum's `rename_all` rule applied.
+pub fn serde_variant_name(variant: &str, attrs: &[Attribute], rename_all: Option<&str>) -> String {
+    if let Some(name) = serde_rename(attrs) {
+        return name;
+    }
+
+    match rename_all {
+        Some("lowercase") => variant.to_lowercase(),
+        Some("UPPERCASE") => variant.to_uppercase(),
+        Some("PascalCase") => variant.to_string(),
+        Some(rule) => {
+            // Other rules apply to the variant's snake_case name, as serde does.
+            let mut snake_case = String::new();
+            for (idx, ch) in variant.char_indices() {
+                if idx > 0 && ch.is_uppercase() {
+                    snake_case.push('_');
+                }
+                snake_case.push(ch.to_ascii_lowercase());
+            }
+            apply_rename_rule(rule, &snake_case)
+        }
+        None => variant.to_string(),
+    }
+}
+
+/// Applies a serde `rename_all` rule to a snake_case field name.
+fn apply_rename_rule(rule: &str, field: &str) -> String {
+    let pascal_case: String = field
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+
+    match rule {
+        "lowercase" | "snake_case" => field.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_uppercase(),
+        "PascalCase" => pascal_case,
+        "camelCase" => {
+            let mut chars = pascal_case.chars();
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.replace('_', "-").to_uppercase(),
+        _ => panic!("Unknown serde rename_all rule: {}", rule),
+    }
+}
+
+/// The serde adapter a field is serialized with: its `with` or `serialize_with` attribute.
+fn serde_adapter(attrs: &[Attribute]) -> Option<String> {
+    name_value(attrs, "serde", "with").or_else(|| name_value(attrs, "serde", "serialize_with"))
+}
+
+/// The type a field is rendered as instead of its own: `#[purs(with = "...")]`, or a serde `with`
+/// adapter named like a type, such as the mirror of a remote type in
+/// `#[serde(with = "DurationDef")]`.
+pub fn adapter_type(attrs: &[Attribute]) -> Option<String> {
+    name_value(attrs, "purs", "with").or_else(|| {
+        let adapter = name_value(attrs, "serde", "with")?;
+        let is_type = adapter
+            .rsplit("::")
+            .next()
+            .and_then(|segment| segment.chars().next())
+            .is_some_and(char::is_uppercase);
+        if is_type {
+            Some(adapter)
+        } else {
+            None
+        }
+    })
+}
+
+/// The path of the type described by a mirror definition: `#[purs(remote = "...")]`, or serde's
+/// `#[serde(remote = "...")]`.
+pub fn remote(attrs: &[Attribute]) -> Option<String> {
+    name_value(attrs, "purs", "remote").or_else(|| name_value(attrs, "serde", "remote"))
+}
+
+/// How a byte buffer field is represented: the value of `#[purs(bytes = "...")]`, or `"base64"`
+/// when the field is serialized with a base64 adapter such as `#[serde(with = "base64")]`.
+pub fn bytes(attrs: &[Attribute]) -> Option<String> {
+    name_value(attrs, "purs", "bytes").or_else(|| {
+        if serde_adapter(attrs)?.to_lowercase().contains("base64") {
+            Some("base64".to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// A date field serialized in another format by its serde adapter.
+pub enum Adapted {
+    /// E.g. `#[serde(with = "time::serde::timestamp")]` or `chrono::serde::ts_seconds`.
+    Required,
+    /// E.g. `#[serde(with = "time::serde::timestamp::option")]` or
+    /// `chrono::serde::ts_seconds_option`.
+    Optional,
+}
+
+/// Whether the field is serialized as an integer timestamp rather than as its type would be.
+pub fn timestamp(attrs: &[Attribute]) -> Option<Adapted> {
+    date_adapter(attrs, |segment| segment == "timestamp" || segment.starts_with("ts_"))
+}
+
+/// Whether the field is serialized as an RFC 3339 string by `time::serde::rfc3339`, unlike the
+/// time crate's default format.
+pub fn rfc3339(attrs: &[Attribute]) -> Option<Adapted> {
+    date_adapter(attrs, |segment| segment == "rfc3339")
+}
+
+/// Whether the field's serde adapter has a path segment matching `is_format`, and whether it is
+/// the adapter for optional fields.
+fn date_adapter<F: Fn(&str) -> bool>(attrs: &[Attribute], is_format: F) -> Option<Adapted> {
+    let adapter = serde_adapter(attrs)?;
+    let segments: Vec<&str> = adapter.split("::").collect();
+    if !segments.iter().any(|segment| is_format(segment)) {
+        return None;
+    }
+
+    let last = segments[segments.len() - 1];
+    if last == "option" || last.ends_with("_option") {
+        Some(Adapted::Optional)
+    } else {
+        Some(Adapted::Required)
+    }
+}
+
+/// The `label_case` set with `#[purs(label_case = "...")]`, as an `Option<LabelCase>`
+/// expression.
+pub struct LabelCase(pub Option<String>);
+
+impl ToTokens for LabelCase {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        let case = match self.0.as_deref() {
+            None => return tokens.append(quote!(None)),
+            Some("preserve") => quote!(Preserve),
+            Some("camelCase") => quote!(CamelCase),
+            Some("snake_case") => quote!(SnakeCase),
+            Some(other) => panic!(
+                "Unknown label_case {:?}, expected \"preserve\", \"camelCase\" or \"snake_case\"",
+                other
+            ),
+        };
+        tokens.append(quote!(Some(::purescript_waterslide::LabelCase::#case)))
+    }
+}
+
+/// The `representation` set with `#[purs(representation = "...")]`, as an
+/// `Option<RecordRepresentation>` expression.
+pub struct Representation(pub Option<String>);
+
+impl ToTokens for Representation {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        let representation = match self.0.as_deref() {
+            None => return tokens.append(quote!(None)),
+            Some("data") => quote!(Data),
+            Some("newtype") => quote!(Newtype),
+            Some("type") => quote!(TypeSynonym),
+            Some(other) => panic!(
+                "Unknown representation {:?}, expected \"data\", \"newtype\" or \"type\"",
+                other
+            ),
+        };
+        tokens.append(quote!(Some(::purescript_waterslide::RecordRepresentation::#representation)))
+    }
+}
+
+/// An optional string, as an `Option<String>` expression.
+pub struct OptionalString(pub Option<String>);
+
+impl ToTokens for OptionalString {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        match self.0 {
+            Some(ref value) => tokens.append(quote!(Some(#value.to_string()))),
+            None => tokens.append(quote!(None)),
+        }
+    }
+}
@@ -48,11 +48,7 @@ pub fn make_dummy_generic(param: &TyParam) -> Tokens {
 
         impl AsPursConstructor for #type_ident {
             fn as_purs_constructor() -> PursConstructor {
-                PursConstructor {
-                    module: None,
-                    name: #type_name.to_string(),
-                    parameters: vec![],
-                }
+                PursConstructor::variable(#type_name)
             }
         }
     }
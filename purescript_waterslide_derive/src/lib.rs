@@ -8,28 +8,24 @@ extern crate quote;
 
 extern crate purescript_waterslide;
 
+mod attributes;
 mod purescript;
 mod generics;
 
 use quote::Tokens;
-use purescript::{make_purs_constructor_impl, make_purs_type};
-
-#[proc_macro_derive(AsPursType)]
-pub fn derive_purstype(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = input.to_string();
-    let ast =
-        syn::parse_derive_input(&input).expect("Purescript waterslide could not parse input type");
+use purescript::{make_purs_constructor_impl, make_purs_type, make_remote_check};
 
+/// The `AsPursConstructor` impl, shared by both derives.
+fn as_purs_constructor_impl(ast: &syn::DeriveInput) -> Tokens {
     let name = &ast.ident;
-    let generics = generics::shift_generics(&ast);
+    let generics = generics::shift_generics(ast);
     let placeholder_generics: Vec<Tokens> = ast.generics
         .ty_params
         .iter()
         .map(generics::make_dummy_generic)
         .collect();
-    let placeholder_generics_clone = placeholder_generics.clone();
 
-    let as_purs_constructor_impl = match make_purs_constructor_impl(&ast) {
+    let as_purs_constructor_impl = match make_purs_constructor_impl(ast) {
         Ok(generated_impl) => generated_impl,
         Err(err) => panic!(
             "Could not convert the input to Purescript type constructor: {:?}",
@@ -37,23 +33,47 @@ pub fn derive_purstype(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
         ),
     };
 
-    let as_purs_impl = match make_purs_type(&ast) {
-        Ok(generated_impl) => generated_impl,
-        Err(err) => panic!("Could not convert the input to Purescript AST: {:?}", err),
-    };
+    let remote_check = make_remote_check(ast);
 
-    let expanded = quote! {
+    quote! {
         impl#generics ::purescript_waterslide::AsPursConstructor for #name#generics {
             fn as_purs_constructor() -> ::purescript_waterslide::PursConstructor {
+                #remote_check
                 #( #placeholder_generics )*
 
                 #as_purs_constructor_impl
             }
         }
+    }
+}
+
+#[proc_macro_derive(AsPursType, attributes(purs))]
+pub fn derive_purstype(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = input.to_string();
+    let ast =
+        syn::parse_derive_input(&input).expect("Purescript waterslide could not parse input type");
+
+    let name = &ast.ident;
+    let generics = generics::shift_generics(&ast);
+    let placeholder_generics: Vec<Tokens> = ast.generics
+        .ty_params
+        .iter()
+        .map(generics::make_dummy_generic)
+        .collect();
+
+    let as_purs_constructor_impl = as_purs_constructor_impl(&ast);
+
+    let as_purs_impl = match make_purs_type(&ast) {
+        Ok(generated_impl) => generated_impl,
+        Err(err) => panic!("Could not convert the input to Purescript AST: {:?}", err),
+    };
+
+    let expanded = quote! {
+        #as_purs_constructor_impl
 
         impl#generics ::purescript_waterslide::AsPursType for #name#generics {
             fn as_purs_type() -> ::purescript_waterslide::PursType {
-                #( #placeholder_generics_clone )*
+                #( #placeholder_generics )*
 
                 #as_purs_impl
             }
@@ -62,3 +82,14 @@ pub fn derive_purstype(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
 
     expanded.parse().unwrap()
 }
+
+/// Only implements `AsPursConstructor`, for types that other declarations refer to but that are
+/// declared elsewhere, e.g. in the module given with `#[purs(module = "...")]`.
+#[proc_macro_derive(AsPursConstructor, attributes(purs))]
+pub fn derive_purs_constructor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = input.to_string();
+    let ast =
+        syn::parse_derive_input(&input).expect("Purescript waterslide could not parse input type");
+
+    as_purs_constructor_impl(&ast).parse().unwrap()
+}
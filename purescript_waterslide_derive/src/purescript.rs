@@ -2,13 +2,101 @@ use syn;
 use syn::{Body, Ident, VariantData};
 use syn::DeriveInput;
 use quote::{ToTokens, Tokens};
+use attributes::{self, Adapted, LabelCase, OptionalString, Representation};
+use generics::shift_generics;
 
 struct VariantName<'a>(&'a syn::Variant);
 
 impl<'a> ToTokens for VariantName<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
         let name = format!("{}", &self.0.ident);
-        tokens.append(quote!(#name.to_string()))
+        tokens.append(quote!(#name))
+    }
+}
+
+/// Parses a path given as an attribute value.
+fn parse_path(path: &str) -> syn::Path {
+    syn::parse_path(path).unwrap_or_else(|err| panic!("Could not parse path {:?}: {}", path, err))
+}
+
+/// A reference to a type synonym by name, for fields marked `#[purs(synonym)]` whose type is an
+/// alias registered with `purs_alias!`. It has no module, so the synonym has to be declared in the
+/// same Purescript module as the type.
+fn synonym_reference(ty: &syn::Ty) -> Tokens {
+    let segment = match *ty {
+        syn::Ty::Path(None, ref path) => path.segments.last(),
+        _ => None,
+    };
+    let segment = segment.expect("#[purs(synonym)] expects the name of a type alias");
+    let name = segment.ident.to_string();
+    let arguments: Vec<&syn::Ty> = match segment.parameters {
+        syn::PathParameters::AngleBracketed(ref data) => data.types.iter().collect(),
+        _ => Vec::new(),
+    };
+    quote! {
+        ::purescript_waterslide::PursConstructor::new(
+            #name,
+            None,
+            vec![
+                #( <#arguments as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor() ),*
+            ],
+        )
+    }
+}
+
+/// The `PursConstructor` of a field's type, taking `#[purs(bytes = "...")]`, `#[purs(with =
+/// "...")]`, `#[purs(synonym)]` and serde adapters into account.
+struct FieldType<'a>(&'a syn::Field);
+
+impl<'a> ToTokens for FieldType<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        let ty = &self.0.ty;
+        if let Some(adapter) = attributes::adapter_type(&self.0.attrs) {
+            let adapter = parse_path(&adapter);
+            return tokens.append(quote!{
+                <#adapter as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+            });
+        }
+        if attributes::word(&self.0.attrs, "purs", "synonym") {
+            return tokens.append(synonym_reference(ty));
+        }
+        let date_adapters = (
+            attributes::timestamp(&self.0.attrs),
+            attributes::rfc3339(&self.0.attrs),
+        );
+        match attributes::bytes(&self.0.attrs).as_deref() {
+            None => match date_adapters {
+                (Some(Adapted::Required), _) => tokens.append(quote!{
+                    <i64 as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+                }),
+                (Some(Adapted::Optional), _) => tokens.append(quote!{
+                    <Option<i64> as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+                }),
+                (None, Some(Adapted::Required)) => tokens.append(quote!{
+                    <::purescript_waterslide::Rfc3339DateTime
+                        as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+                }),
+                (None, Some(Adapted::Optional)) => tokens.append(quote!{
+                    <Option<::purescript_waterslide::Rfc3339DateTime>
+                        as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+                }),
+                (None, None) => tokens.append(quote!{
+                    <#ty as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+                }),
+            },
+            Some("base64") => tokens.append(quote!{
+                <::purescript_waterslide::Base64Bytes
+                    as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+            }),
+            Some("array_buffer") => tokens.append(quote!{
+                <::purescript_waterslide::ArrayBufferBytes
+                    as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
+            }),
+            Some(other) => panic!(
+                "Unknown bytes representation {:?}, expected \"base64\" or \"array_buffer\"",
+                other
+            ),
+        }
     }
 }
 
@@ -17,12 +105,13 @@ struct VariantArguments<'a>(&'a syn::Variant);
 impl<'a> ToTokens for VariantArguments<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
         if let VariantData::Tuple(ref fields) = self.0.data {
-            let tys = fields.iter().map(|f| &f.ty);
+            let tys = fields
+                .iter()
+                .filter(|field| !attributes::skipped(&field.attrs))
+                .map(FieldType);
             tokens.append(quote!{
                 vec![
-                    #( <
-                       #tys as ::purescript_waterslide::AsPursConstructor
-                       >::as_purs_constructor()  ),*
+                    #( #tys ),*
                 ]
             })
         } else {
@@ -31,18 +120,90 @@ impl<'a> ToTokens for VariantArguments<'a> {
     }
 }
 
-struct TupleField<'a>(&'a syn::Field);
+/// The discriminant of a variant, for enums that serialize as integers (e.g. with `serde_repr`):
+/// its value if the derive could compute it from literals, else the variant cast to an integer,
+/// checked at compile time.
+struct VariantDiscriminant<'a>(&'a Ident, &'a syn::Variant, Option<Option<i64>>);
 
-impl<'a> ToTokens for TupleField<'a> {
+impl<'a> ToTokens for VariantDiscriminant<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
-        let ty = &self.0.ty;
-        tokens.append(quote!{
-            <#ty as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
-        })
+        let VariantDiscriminant(enum_name, variant, discriminant) = *self;
+        let variant_name = &variant.ident;
+        match discriminant {
+            None => tokens.append(quote!(None)),
+            Some(Some(value)) => tokens.append(quote!(Some(#value))),
+            Some(None) => {
+                let message = format!(
+                    "The discriminant of {}::{} is outside the range of a Purescript Int",
+                    enum_name, variant_name
+                );
+                tokens.append(quote!(Some({
+                    const _: () = assert!(
+                        (#enum_name::#variant_name as i128) >= (::std::i32::MIN as i128)
+                            && (#enum_name::#variant_name as i128) <= (::std::i32::MAX as i128),
+                        #message
+                    );
+                    #enum_name::#variant_name as i64
+                })))
+            }
+        }
+    }
+}
+
+/// The value of a discriminant written as an integer literal, possibly negated.
+fn literal_discriminant(expr: &syn::ConstExpr) -> Option<i128> {
+    match *expr {
+        syn::ConstExpr::Lit(syn::Lit::Int(value, _)) => Some(i128::from(value)),
+        syn::ConstExpr::Unary(syn::UnOp::Neg, ref expr) => literal_discriminant(expr).map(|v| -v),
+        syn::ConstExpr::Paren(ref expr) => literal_discriminant(expr),
+        _ => None,
+    }
+}
+
+/// The discriminants of the variants, `None` for those following an expression the derive can't
+/// evaluate. Discriminants outside the range of a Purescript `Int` are an error, since Purescript
+/// couldn't decode them.
+fn discriminants(enum_name: &Ident, variants: &[syn::Variant]) -> Result<Vec<Option<i64>>, String> {
+    let mut next = Some(0);
+    let mut discriminants = Vec::new();
+    for variant in variants {
+        let discriminant = match variant.discriminant {
+            Some(ref expr) => literal_discriminant(expr),
+            None => next,
+        };
+        if let Some(value) = discriminant {
+            if value < i128::from(i32::MIN) || value > i128::from(i32::MAX) {
+                return Err(format!(
+                    "The discriminant of {}::{} is {}, outside the range of a Purescript Int",
+                    enum_name, variant.ident, value
+                ));
+            }
+        }
+        discriminants.push(discriminant.map(|value| value as i64));
+        next = discriminant.map(|value| value + 1);
     }
+    Ok(discriminants)
+}
+
+/// Whether the enum is marked `#[purs(int_enum)]`, for enums serialized as integers, e.g. with
+/// `serde_repr`. Their variants have to be units.
+fn is_integer_encoded(source: &DeriveInput, variants: &[syn::Variant]) -> Result<bool, String> {
+    if !attributes::word(&source.attrs, "purs", "int_enum") {
+        return Ok(false);
+    }
+    if variants.iter().any(|v| v.data != VariantData::Unit) {
+        return Err(format!(
+            "#[purs(int_enum)] is only supported on enums whose variants are all units, which {} \
+             isn't",
+            source.ident
+        ));
+    }
+    Ok(true)
 }
 
-struct RecordField<'a>(&'a syn::Field);
+
+/// A record field, and the container's serde `rename_all` rule.
+struct RecordField<'a>(&'a syn::Field, Option<&'a str>);
 
 impl<'a> ToTokens for RecordField<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
@@ -51,12 +212,37 @@ impl<'a> ToTokens for RecordField<'a> {
             .clone()
             .map(|id| format!("{}", id))
             .unwrap_or("_unknown".to_string());
-        let ty = &self.0.ty;
+        let name = attributes::serde_name(&name, &self.0.attrs, self.1);
+        let type_ = FieldType(self.0);
+        let doc = OptionalString(attributes::doc(&self.0.attrs));
         tokens.append(quote!{
-            (
-                #name.to_string(),
-                <#ty as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor()
-            )
+            ::purescript_waterslide::PursField {
+                name: #name.to_string(),
+                type_: #type_,
+                doc: #doc,
+            }
+        })
+    }
+}
+
+/// The `PursAttributes` of the declaration.
+struct Attributes<'a>(&'a DeriveInput);
+
+impl<'a> ToTokens for Attributes<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        let doc = OptionalString(attributes::doc(&self.0.attrs));
+        let label_case = LabelCase(attributes::name_value(&self.0.attrs, "purs", "label_case"));
+        let representation = Representation(attributes::name_value(
+            &self.0.attrs,
+            "purs",
+            "representation",
+        ));
+        tokens.append(quote!{
+            ::purescript_waterslide::PursAttributes {
+                doc: #doc,
+                label_case: #label_case,
+                representation: #representation,
+            }
         })
     }
 }
@@ -64,27 +250,76 @@ impl<'a> ToTokens for RecordField<'a> {
 pub fn make_purs_type(source: &DeriveInput) -> Result<Tokens, String> {
     let name = &source.ident;
     let generics = &source.generics;
+    let attributes = Attributes(source);
+    let opaque = attributes::word(&source.attrs, "purs", "opaque");
+    if !opaque && attributes::name_value(&source.attrs, "purs", "module").is_some() {
+        return Err(
+            "#[purs(module = \"...\")] is only supported on opaque types and with \
+             #[derive(AsPursConstructor)]"
+                .to_string(),
+        );
+    }
+    if opaque {
+        return Ok(quote! {
+            ::purescript_waterslide::PursType::Opaque(
+                <
+                #name#generics as ::purescript_waterslide::AsPursConstructor
+                >::as_purs_constructor(),
+                #attributes,
+            )
+        });
+    }
     match source.body {
         Body::Enum(ref variants) => {
             let variant_names = variants.iter().map(VariantName);
+            let rename_all = attributes::name_value(&source.attrs, "serde", "rename_all");
+            let variant_json_names = variants.iter().map(|variant| {
+                attributes::serde_variant_name(
+                    variant.ident.as_ref(),
+                    &variant.attrs,
+                    rename_all.as_deref(),
+                )
+            });
             let variant_arguments = variants.iter().map(VariantArguments);
+            let discriminants = if is_integer_encoded(source, variants)? {
+                discriminants(name, variants)?.into_iter().map(Some).collect()
+            } else {
+                vec![None; variants.len()]
+            };
+            let variant_discriminants = variants
+                .iter()
+                .zip(discriminants)
+                .map(|(variant, discriminant)| VariantDiscriminant(name, variant, discriminant));
+            let variant_docs = variants
+                .iter()
+                .map(|variant| OptionalString(attributes::doc(&variant.attrs)));
             Ok(quote! {
                 ::purescript_waterslide::PursType::Enum(
                     <
                     #name#generics as ::purescript_waterslide::AsPursConstructor
                     >::as_purs_constructor(),
                     vec![
-                        #( ::purescript_waterslide::PursConstructor {
-                            name: #variant_names,
-                            module: None,
-                            parameters: #variant_arguments,
+                        #( ::purescript_waterslide::PursVariant {
+                            name: #variant_json_names.to_string(),
+                            constructor: ::purescript_waterslide::PursConstructor::new(
+                                #variant_names,
+                                None,
+                                #variant_arguments,
+                            ),
+                            discriminant: #variant_discriminants,
+                            doc: #variant_docs,
                         } ),*
                     ],
+                    #attributes,
                 )
             })
         }
         Body::Struct(VariantData::Struct(ref fields)) => {
-            let purs_record_fields = fields.into_iter().map(RecordField);
+            let rename_all = attributes::name_value(&source.attrs, "serde", "rename_all");
+            let purs_record_fields = fields
+                .iter()
+                .filter(|field| !attributes::skipped(&field.attrs))
+                .map(|field| RecordField(field, rename_all.as_deref()));
             Ok(quote! {
                 ::purescript_waterslide::PursType::Struct(
                     <
@@ -93,11 +328,15 @@ pub fn make_purs_type(source: &DeriveInput) -> Result<Tokens, String> {
                     vec![
                         #( #purs_record_fields ),*
                     ],
+                    #attributes,
                 )
             })
         }
         Body::Struct(VariantData::Tuple(ref fields)) => {
-            let purs_tuple_fields = fields.iter().map(TupleField);
+            let purs_tuple_fields = fields
+                .iter()
+                .filter(|field| !attributes::skipped(&field.attrs))
+                .map(FieldType);
             Ok(quote! {
                 ::purescript_waterslide::PursType::TupleStruct(
                     <
@@ -106,36 +345,69 @@ pub fn make_purs_type(source: &DeriveInput) -> Result<Tokens, String> {
                     vec![
                         #( #purs_tuple_fields ),*
                     ],
+                    #attributes,
                 )
             })
         }
         Body::Struct(VariantData::Unit) => Ok(quote!(
                 ::purescript_waterslide::PursType::TupleStruct(
                     <#name as ::purescript_waterslide::AsPursConstructor>::as_purs_constructor(),
-                    vec![]
+                    vec![],
+                    #attributes,
                 )
             )),
     }
 }
 
+/// The name of the Purescript type: `#[purs(name = "...")]`, else the name of the remote type for
+/// mirror definitions, else the name of the Rust type.
+fn purs_name(ast: &DeriveInput) -> String {
+    attributes::name_value(&ast.attrs, "purs", "name")
+        .or_else(|| {
+            attributes::remote(&ast.attrs)
+                .and_then(|remote| parse_path(&remote).segments.last().map(|s| s.ident.to_string()))
+        })
+        .unwrap_or_else(|| ast.ident.to_string())
+}
+
+/// For mirror definitions, a statement that only compiles if the remote type exists with the
+/// mirror's type parameters, since nothing else refers to it.
+pub fn make_remote_check(ast: &DeriveInput) -> Tokens {
+    match attributes::remote(&ast.attrs) {
+        Some(remote) => {
+            let remote = parse_path(&remote);
+            let generics = shift_generics(ast);
+            let (_, ty_generics, _) = generics.split_for_impl();
+            quote! {
+                let _: ::std::marker::PhantomData<#remote#ty_generics> = ::std::marker::PhantomData;
+            }
+        }
+        None => quote!(),
+    }
+}
+
 pub fn make_purs_constructor_impl(ast: &DeriveInput) -> Result<Tokens, String> {
-    let name = format!("{}", &ast.ident);
+    let name = purs_name(ast);
+    let module = match attributes::name_value(&ast.attrs, "purs", "module") {
+        Some(module) => quote!(Some(#module)),
+        None => quote!(None),
+    };
     let parameters: Vec<Ident> = ast.generics
         .ty_params
         .iter()
         .map(|param| param.ident.clone())
         .collect();
     Ok(quote! {
-        ::purescript_waterslide::PursConstructor {
-            name: #name.to_string(),
-            module: None,
-            parameters: vec![
+        ::purescript_waterslide::PursConstructor::new(
+            #name,
+            #module,
+            vec![
                 #(
                     <
                     #parameters as ::purescript_waterslide::AsPursConstructor
                     >::as_purs_constructor()
                 ),*
             ],
-        }
+        )
     })
 }
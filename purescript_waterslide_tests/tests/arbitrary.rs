@@ -0,0 +1,246 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+extern crate chrono;
+extern crate serde_json;
+extern crate void;
+
+use void::Void;
+use purescript_waterslide::*;
+
+#[test]
+fn module_with_arbitrary_instances() {
+    #[derive(AsPursType)]
+    enum Currency {
+        Coins,
+        Credits(i32),
+    }
+
+    #[derive(AsPursType)]
+    struct Price<T> {
+        amount: i32,
+        currency: Currency,
+        extra: T,
+    }
+
+    #[derive(AsPursType)]
+    struct Range(i32, i32);
+
+    #[derive(AsPursType)]
+    struct Free;
+
+    let module = purs_module!("Prices".to_string() ; Currency, Price<Void>, Range, Free)
+        .with_arbitrary_instances();
+    assert_eq!(
+        &format!("{}", &module),
+        "module Prices where
+
+import Prelude
+import Data.Array.NonEmpty (
+  cons'
+)
+import Data.Generic (
+  class Generic
+)
+import Test.QuickCheck.Arbitrary (
+  class Arbitrary,
+  arbitrary
+)
+import Test.QuickCheck.Gen (
+  oneOf,
+  resize,
+  sized
+)
+
+data Currency = Coins | Credits Int
+
+derive instance genericCurrency :: Generic Currency
+
+instance arbitraryCurrency :: Arbitrary Currency where
+  arbitrary = oneOf (cons' (pure Coins) [(Credits <$> arbitrary)])
+
+data Price t = Price { amount :: Int, currency :: Currency, extra :: t }
+
+derive instance genericPrice :: Generic Price
+
+instance arbitraryPrice :: Arbitrary t => Arbitrary (Price t) where
+  arbitrary = do
+    v0 <- arbitrary
+    v1 <- arbitrary
+    v2 <- arbitrary
+    pure (Price { amount: v0, currency: v1, extra: v2 })
+
+data Range = Range Int Int
+
+derive instance genericRange :: Generic Range
+
+instance arbitraryRange :: Arbitrary Range where
+  arbitrary = Range <$> arbitrary <*> arbitrary
+
+data Free = Free
+
+derive instance genericFree :: Generic Free
+
+instance arbitraryFree :: Arbitrary Free where
+  arbitrary = pure Free
+"
+    );
+}
+
+#[test]
+fn recursive_enum_generation_terminates() {
+    #[derive(AsPursType)]
+    enum Tree {
+        Node(Box<Tree>, Box<Tree>),
+        Leaf(i32),
+    }
+
+    let module = purs_module!("Trees".to_string() ; Tree).with_arbitrary_instances();
+    assert!(format!("{}", &module).contains(
+        "instance arbitraryTree :: Arbitrary Tree where
+  arbitrary = sized \\size ->
+    if size <= 0
+      then oneOf (cons' (Leaf <$> arbitrary) [])
+      else resize (size - 1) (oneOf (cons' (Node <$> arbitrary <*> arbitrary) [(Leaf <$> arbitrary)]))
+"
+    ));
+}
+
+#[test]
+fn collection_generators() {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(AsPursType)]
+    struct Inventory {
+        by_name: HashMap<String, i32>,
+        by_id: Vec<BTreeMap<i32, String>>,
+        tags: ::std::collections::BTreeSet<String>,
+    }
+
+    let module = purs_module!("Inventory".to_string() ; Inventory).with_arbitrary_instances();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Foreign.Object as Object\n"));
+    assert!(output.contains("import Data.Set as Set\n"));
+    assert!(output.contains(
+        "    v0 <- (Object.fromFoldable <$> arrayOf (Tuple <$> arbitrary <*> arbitrary))
+    v1 <- (arrayOf (Map.fromFoldable <$> arrayOf (Tuple <$> arbitrary <*> arbitrary)))
+    v2 <- (Set.fromFoldable <$> arrayOf arbitrary)
+"
+    ));
+}
+
+#[test]
+fn byte_json_and_uninhabited_generators() {
+    #[derive(AsPursType)]
+    struct Upload {
+        raw: ArrayBufferBytes,
+        encoded: Base64Bytes,
+        metadata: serde_json::Value,
+        never: Option<::std::convert::Infallible>,
+        nothing: Vec<::std::convert::Infallible>,
+    }
+
+    let module = purs_module!("Uploads".to_string() ; Upload)
+        .with_json_values(JsonValues::Foreign)
+        .with_arbitrary_instances();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.ArrayBuffer.Typed as Typed\n"));
+    assert!(output.contains("import Foreign (\n  Foreign,\n  unsafeToForeign\n)\n"));
+    assert!(!output.contains("unsafeCrashWith"));
+    assert!(output.contains(
+        "    v0 <- (unsafePerformEffect <<< Typed.fromArray <<< map UInt.fromInt <$> arrayOf (chooseInt 0 255))
+    v1 <- (Base64.encode <$> (unsafePerformEffect <<< Typed.fromArray <<< map UInt.fromInt <$> arrayOf (chooseInt 0 255)))
+    v2 <- (unsafeToForeign <$> genJson)
+    v3 <- (pure Nothing)
+    v4 <- (pure mempty)
+"
+    ));
+}
+
+#[test]
+fn void_fields_crash_when_generated() {
+    #[derive(AsPursType)]
+    struct Impossible {
+        never: ::std::convert::Infallible,
+    }
+
+    let module = purs_module!("Impossible".to_string() ; Impossible).with_arbitrary_instances();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Partial.Unsafe (\n  unsafeCrashWith\n)\n"));
+    assert!(output.contains(
+        "    v0 <- (sized \\_ -> unsafeCrashWith \"Void has no values\")\n"
+    ));
+}
+
+#[test]
+fn date_generators() {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    #[derive(AsPursType)]
+    struct Shift {
+        day: NaiveDate,
+        start: NaiveTime,
+        handover: Option<NaiveDateTime>,
+    }
+
+    let module = purs_module!("Shifts".to_string() ; Shift)
+        .with_dates(Dates::DateTime)
+        .with_arbitrary_instances();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Enum.Gen (\n  genBoundedEnum\n)\n"));
+    assert!(output.contains(
+        "    v0 <- (Date.canonicalDate <$> (toEnumWithDefaults bottom top <$> chooseInt 1 9999) <*> genBoundedEnum <*> genBoundedEnum)
+    v1 <- (Time.Time <$> genBoundedEnum <*> genBoundedEnum <*> genBoundedEnum <*> genBoundedEnum)
+    v2 <- (oneOf (cons' (pure Nothing) [Just <$> (DateTime.DateTime <$> (Date.canonicalDate"
+    ));
+}
+
+#[test]
+fn mutually_recursive_generation_terminates() {
+    #[derive(AsPursType)]
+    enum Expr {
+        Literal(i32),
+        Block(Block),
+    }
+
+    #[derive(AsPursType)]
+    struct Block {
+        statements: Vec<Expr>,
+    }
+
+    let module = purs_module!("Exprs".to_string() ; Expr, Block).with_arbitrary_instances();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "instance arbitraryExpr :: Arbitrary Expr where
+  arbitrary = sized \\size ->
+    if size <= 0
+      then oneOf (cons' (Literal <$> arbitrary) [])
+      else resize (size - 1) (oneOf (cons' (Literal <$> arbitrary) [(Block <$> arbitrary)]))
+"
+    ));
+    assert!(output.contains(
+        "instance arbitraryBlock :: Arbitrary Block where
+  arbitrary = sized \\size -> resize (max 0 (size - 1)) $ do
+    v0 <- arbitrary
+    pure (Block { statements: v0 })
+"
+    ));
+}
+
+#[test]
+fn empty_enum_generation_crashes() {
+    #[derive(AsPursType)]
+    enum Never {}
+
+    let module = purs_module!("Nothing".to_string() ; Never).with_arbitrary_instances();
+    let output = format!("{}", &module);
+    println!("{}", output);
+    assert!(output.contains("import Partial.Unsafe (\n  unsafeCrashWith\n)\n"));
+    assert!(output.contains(
+        "instance arbitraryNever :: Arbitrary Never where
+  arbitrary = unsafeCrashWith \"Never has no values\"
+"
+    ));
+}
@@ -0,0 +1,796 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+extern crate chrono;
+extern crate either;
+extern crate indexmap;
+extern crate num_bigint;
+extern crate rust_decimal;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate void;
+
+use void::Void;
+use purescript_waterslide::*;
+
+#[test]
+fn integer_enum_functions() {
+    #[derive(AsPursType)]
+    #[repr(i8)]
+    #[purs(int_enum)]
+    enum Level {
+        Low = -1,
+        High = 10,
+    }
+
+    let module = purs_module!("Levels".to_string() ; Level);
+    assert_eq!(
+        &format!("{}", &module),
+        "module Levels where
+
+import Data.Generic (
+  class Generic
+)
+import Data.Maybe (
+  Maybe(..)
+)
+
+data Level = Low | High
+
+derive instance genericLevel :: Generic Level
+
+levelToInt :: Level -> Int
+levelToInt Low = -1
+levelToInt High = 10
+
+levelFromInt :: Int -> Maybe Level
+levelFromInt (-1) = Just Low
+levelFromInt 10 = Just High
+levelFromInt _ = Nothing
+"
+    );
+}
+
+#[test]
+fn integer_enum_codecs() {
+    #[derive(AsPursType)]
+    #[repr(u8)]
+    #[purs(int_enum)]
+    enum Level {
+        Low = 1,
+        High = 10,
+    }
+
+    let module = purs_module!("Levels".to_string() ; Level).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "instance encodeJsonLevel :: EncodeJson Level where
+  encodeJson value = encodeJson (levelToInt value)
+"
+    ));
+    assert!(output.contains(
+        "instance decodeJsonLevel :: DecodeJson Level where
+  decodeJson json = do
+    n <- decodeJson json
+    case levelFromInt n of
+      Just value -> Right value
+      Nothing -> Left (UnexpectedValue json)
+"
+    ));
+}
+
+#[test]
+fn module_with_codecs() {
+    #[derive(AsPursType)]
+    enum Dessert {
+        IceCream,
+        Pie(String),
+        Cake(u8, Option<String>),
+    }
+
+    #[derive(AsPursType)]
+    struct Order<T> {
+        dessert: Dessert,
+        table: Option<u8>,
+        extra: T,
+    }
+
+    #[derive(AsPursType)]
+    struct Table(u8, u8);
+
+    #[derive(AsPursType)]
+    struct Empty;
+
+    let module = purs_module!("Desserts".to_string() ; Dessert, Order<Void>, Table, Empty)
+        .with_codecs();
+    assert_eq!(
+        &format!("{}", &module),
+        "module Desserts where
+
+import Prelude
+import Data.Argonaut.Core (
+  fromArray,
+  fromObject,
+  fromString,
+  isNull,
+  jsonNull,
+  toArray,
+  toObject,
+  toString
+)
+import Data.Argonaut.Decode (
+  class DecodeJson,
+  JsonDecodeError(..),
+  decodeJson,
+  (.:),
+  (.:?)
+)
+import Data.Argonaut.Encode (
+  class EncodeJson,
+  encodeJson
+)
+import Data.Either (
+  Either(..)
+)
+import Data.Generic (
+  class Generic
+)
+import Data.Maybe (
+  Maybe(..)
+)
+import Data.Tuple (
+  Tuple(..)
+)
+import Foreign.Object (
+  fromFoldable,
+  singleton,
+  toUnfoldable
+)
+
+data Dessert = IceCream | Pie String | Cake Int (Maybe String)
+
+derive instance genericDessert :: Generic Dessert
+
+instance encodeJsonDessert :: EncodeJson Dessert where
+  encodeJson value = case value of
+    IceCream -> fromString \"IceCream\"
+    (Pie a0) -> fromObject (singleton \"Pie\" (encodeJson a0))
+    (Cake a0 a1) -> fromObject (singleton \"Cake\" (fromArray [encodeJson a0, encodeJson a1]))
+
+instance decodeJsonDessert :: DecodeJson Dessert where
+  decodeJson json = case toString json of
+    Just \"IceCream\" -> Right IceCream
+    _ -> case map toUnfoldable (toObject json) of
+      Just [Tuple \"Pie\" value] -> Pie <$> decodeJson value
+      Just [Tuple \"Cake\" value] -> case toArray value of
+        Just [a0, a1] -> Cake <$> decodeJson a0 <*> decodeJson a1
+        _ -> Left (UnexpectedValue value)
+      _ -> Left (UnexpectedValue json)
+
+data Order t = Order { dessert :: Dessert, table :: Maybe Int, extra :: t }
+
+derive instance genericOrder :: Generic Order
+
+instance encodeJsonOrder :: EncodeJson t => EncodeJson (Order t) where
+  encodeJson (Order record) = fromObject (fromFoldable
+    [ Tuple \"dessert\" (encodeJson record.dessert)
+    , Tuple \"table\" (encodeJson record.table)
+    , Tuple \"extra\" (encodeJson record.extra)
+    ]
+  )
+
+instance decodeJsonOrder :: DecodeJson t => DecodeJson (Order t) where
+  decodeJson json = do
+    object <- decodeJson json
+    v0 <- object .: \"dessert\"
+    v1 <- object .:? \"table\"
+    v2 <- object .: \"extra\"
+    pure (Order { dessert: v0, table: v1, extra: v2 })
+
+data Table = Table Int Int
+
+derive instance genericTable :: Generic Table
+
+instance encodeJsonTable :: EncodeJson Table where
+  encodeJson (Table a0 a1) = (fromArray [encodeJson a0, encodeJson a1])
+
+instance decodeJsonTable :: DecodeJson Table where
+  decodeJson json = case toArray json of
+    Just [a0, a1] -> Table <$> decodeJson a0 <*> decodeJson a1
+    _ -> Left (UnexpectedValue json)
+
+data Empty = Empty
+
+derive instance genericEmpty :: Generic Empty
+
+instance encodeJsonEmpty :: EncodeJson Empty where
+  encodeJson Empty = jsonNull
+
+instance decodeJsonEmpty :: DecodeJson Empty where
+  decodeJson json
+    | isNull json = Right Empty
+    | otherwise = Left (TypeMismatch \"Null\")
+"
+    );
+}
+
+#[test]
+fn map_codecs() {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(AsPursType)]
+    struct Inventory {
+        by_name: HashMap<String, i32>,
+        by_id: BTreeMap<i32, String>,
+        history: Option<Vec<BTreeMap<i32, bool>>>,
+    }
+
+    let module = purs_module!("Inventory".to_string() ; Inventory).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Map (\n  Map\n)\n"));
+    assert!(output.contains("import Data.Map as Map\n"));
+    assert!(output.contains("import Foreign.Object (\n  Object,\n"));
+    assert!(output.contains(
+        "data Inventory = Inventory { by_name :: Object Int, by_id :: Map Int String, history :: Maybe (Array (Map Int Boolean)) }"
+    ));
+    assert!(output.contains(
+        "    [ Tuple \"by_name\" (encodeJson record.by_name)
+    , Tuple \"by_id\" ((encodeMap encodeJson encodeJson) record.by_id)
+    , Tuple \"history\" ((maybe jsonNull (encodeJson <<< map (encodeMap encodeJson encodeJson))) record.history)
+"
+    ));
+    assert!(output.contains(
+        "    v0 <- object .: \"by_name\"
+    v1 <- (decodeMap decodeJson decodeJson) =<< object .: \"by_id\"
+    v2 <- traverse (traverse (decodeMap decodeJson decodeJson) <=< decodeJson) =<< object .:? \"history\"
+"
+    ));
+    assert!(output.contains("\nencodeMap :: forall k v. (k -> Json) -> (v -> Json) -> Map k v -> Json\n"));
+    assert!(output.contains("\ndecodeMapKey decodeKey key = case decodeKey (fromString key) of\n"));
+}
+
+#[test]
+fn string_keyed_maps_need_no_helpers() {
+    use std::collections::HashMap;
+
+    #[derive(AsPursType)]
+    struct Labels {
+        labels: HashMap<String, String>,
+    }
+
+    let module = purs_module!("Labels".to_string() ; Labels).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("    v0 <- object .: \"labels\"\n"));
+    assert!(!output.contains("encodeMap"));
+    assert!(!output.contains("Data.Map"));
+}
+
+#[test]
+fn result_codecs() {
+    #[derive(AsPursType)]
+    enum Outcome {
+        Single(Result<i32, String>),
+        Batch(Vec<Result<i32, String>>),
+    }
+
+    let module = purs_module!("Batches".to_string() ; Outcome).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("data Outcome = Single (Either String Int) | Batch (Array (Either String Int))"));
+    assert!(output.contains(
+        "    (Single a0) -> fromObject (singleton \"Single\" ((encodeResult encodeJson encodeJson) a0))
+    (Batch a0) -> fromObject (singleton \"Batch\" ((encodeJson <<< map (encodeResult encodeJson encodeJson)) a0))
+"
+    ));
+    assert!(output.contains(
+        "    Just [Tuple \"Single\" value] -> Single <$> (decodeResult decodeJson decodeJson) value
+    Just [Tuple \"Batch\" value] -> Batch <$> (traverse (decodeResult decodeJson decodeJson) <=< decodeJson) value
+"
+    ));
+    assert!(output.ends_with(
+        "
+encodeResult :: forall e t. (e -> Json) -> (t -> Json) -> Either e t -> Json
+encodeResult encodeErr encodeOk = either
+  (\\err -> fromObject (singleton \"Err\" (encodeErr err)))
+  (\\ok -> fromObject (singleton \"Ok\" (encodeOk ok)))
+
+decodeResult :: forall e t. (Json -> Either JsonDecodeError e) -> (Json -> Either JsonDecodeError t) -> Json -> Either JsonDecodeError (Either e t)
+decodeResult decodeErr decodeOk json = case map toUnfoldable (toObject json) of
+  Just [Tuple \"Ok\" value] -> Right <$> decodeOk value
+  Just [Tuple \"Err\" value] -> Left <$> decodeErr value
+  _ -> Left (UnexpectedValue json)
+"
+    ));
+    assert!(output.contains("import Data.Either (\n  Either(..),\n  either\n)\n"));
+    assert!(!output.contains("encodeMap"));
+}
+
+#[test]
+fn tuple_codecs() {
+    #[derive(AsPursType)]
+    struct Segment(u8, (f64, f64, f64), (u8, String));
+
+    let module = purs_module!("Segments".to_string() ; Segment).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "  encodeJson (Segment a0 a1 a2) = (fromArray [encodeJson a0, (encodeTuple3 encodeJson encodeJson encodeJson) a1, encodeJson a2])\n"
+    ));
+    assert!(output.contains(
+        "    Just [a0, a1, a2] -> Segment <$> decodeJson a0 <*> (decodeTuple3 decodeJson decodeJson decodeJson) a1 <*> decodeJson a2\n"
+    ));
+    assert!(output.ends_with(
+        "
+encodeTuple3 :: forall a b c. (a -> Json) -> (b -> Json) -> (c -> Json) -> (Tuple3 a b c) -> Json
+encodeTuple3 encodeA encodeB encodeC (Tuple a (Tuple b (Tuple c _))) = fromArray [encodeA a, encodeB b, encodeC c]
+
+decodeTuple3 :: forall a b c. (Json -> Either JsonDecodeError a) -> (Json -> Either JsonDecodeError b) -> (Json -> Either JsonDecodeError c) -> Json -> Either JsonDecodeError (Tuple3 a b c)
+decodeTuple3 decodeA decodeB decodeC json = case toArray json of
+  Just [a, b, c] -> Tuple <$> decodeA a <*> (Tuple <$> decodeB b <*> (Tuple <$> decodeC c <*> pure unit))
+  _ -> Left (UnexpectedValue json)
+"
+    ));
+    assert!(!output.contains("encodeTuple "));
+
+    let module = purs_module!("Segments".to_string() ; Segment)
+        .with_nested_tuples()
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "encodeT3 encodeA encodeB encodeC (Tuple a (Tuple b c)) = fromArray [encodeA a, encodeB b, encodeC c]\n"
+    ));
+    assert!(output.contains(
+        "  Just [a, b, c] -> Tuple <$> decodeA a <*> (Tuple <$> decodeB b <*> decodeC c)\n"
+    ));
+}
+
+#[test]
+fn empty_enum_codecs() {
+    #[derive(AsPursType)]
+    enum Never {}
+
+    let module = purs_module!("Nothing".to_string() ; Never).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("\ndata Never\n"));
+    assert!(output.contains("  encodeJson _ = jsonNull\n"));
+    assert!(output.contains("  decodeJson json = Left (UnexpectedValue json)\n"));
+}
+
+#[test]
+fn big_int_codecs() {
+    #[derive(AsPursType)]
+    struct Counter(Option<u64>);
+
+    let module = purs_module!("Counters".to_string() ; Counter)
+        .with_wide_integers(WideIntegers::BigInt)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import JS.BigInt as BigInt\n"));
+    assert!(output.contains("  encodeJson (Counter a0) = ((maybe jsonNull encodeBigInt) a0)\n"));
+    assert!(output.contains(
+        "  decodeJson json = Counter <$> (\\json -> if isNull json then Right Nothing else Just <$> decodeBigInt json) json\n"
+    ));
+    assert!(output.ends_with(
+        "
+encodeBigInt :: BigInt -> Json
+encodeBigInt = fromString <<< BigInt.toString
+
+decodeBigInt :: Json -> Either JsonDecodeError BigInt
+decodeBigInt json = note (UnexpectedValue json) $ case toString json of
+  Just string -> BigInt.fromString string
+  Nothing -> BigInt.fromNumber =<< toNumber json
+"
+    ));
+}
+
+/// Serializes integers as strings, like serde_with's `DisplayFromStr`.
+mod as_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(::serde::de::Error::custom)
+    }
+}
+
+#[test]
+fn big_int_codecs_keep_integers_above_2_53() {
+    #[derive(AsPursType, Serialize, Deserialize, Debug, PartialEq)]
+    struct Balance {
+        #[serde(with = "as_string")]
+        cents: u64,
+    }
+
+    let balance = Balance { cents: (1 << 53) + 1 };
+    let json = serde_json::to_string(&balance).unwrap();
+    assert_eq!(json, r#"{"cents":"9007199254740993"}"#);
+    assert_eq!(serde_json::from_str::<Balance>(&json).unwrap(), balance);
+
+    // The generated codecs write and read the same JSON strings, without going through `Number`.
+    let module = purs_module!("Balances".to_string() ; Balance)
+        .with_wide_integers(WideIntegers::BigInt)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("encodeBigInt = fromString <<< BigInt.toString\n"));
+    assert!(output.contains("  Just string -> BigInt.fromString string\n"));
+    assert!(!output.contains("BigInt.toNumber"));
+}
+
+#[test]
+fn byte_buffer_codecs() {
+    #[derive(AsPursType)]
+    struct Upload {
+        #[purs(bytes = "base64")]
+        content: Vec<u8>,
+        thumbnail: Option<ArrayBufferBytes>,
+    }
+
+    let module = purs_module!("Uploads".to_string() ; Upload).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.ArrayBuffer.Types (\n  Uint8Array\n)\n"));
+    assert!(output.contains("import Data.Binary.Base64 as Base64\n"));
+    assert!(!output.contains("PRIM"));
+    assert!(output.contains(
+        "    [ Tuple \"content\" (encodeBase64 record.content)
+    , Tuple \"thumbnail\" ((maybe jsonNull encodeUint8Array) record.thumbnail)
+"
+    ));
+    assert!(output.contains(
+        "    v0 <- decodeBase64 =<< object .: \"content\"
+    v1 <- traverse decodeUint8Array =<< object .:? \"thumbnail\"
+"
+    ));
+    assert!(output.contains(
+        "decodeBase64 :: Json -> Either JsonDecodeError String
+decodeBase64 json = do
+  string <- decodeJson json
+  if String.length string `mod` 4 == 0 && Regex.test base64Pattern string
+    then Right string
+    else Left (UnexpectedValue json)
+"
+    ));
+    assert!(output.contains(
+        "  either (const (Left (UnexpectedValue json))) Right (Base64.decode string)\n"
+    ));
+}
+
+#[test]
+fn date_codecs() {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+    #[derive(AsPursType)]
+    struct Meeting {
+        day: NaiveDate,
+        created_at: DateTime<Utc>,
+        scheduled_at: Option<DateTime<FixedOffset>>,
+        reminders: Vec<NaiveDateTime>,
+    }
+
+    let module = purs_module!("Meetings".to_string() ; Meeting)
+        .with_dates(Dates::DateTime)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "    [ Tuple \"day\" (encodeDate record.day)
+    , Tuple \"created_at\" (encodeDateTime record.created_at)
+    , Tuple \"scheduled_at\" ((maybe jsonNull encodeDateTime) record.scheduled_at)
+    , Tuple \"reminders\" ((encodeJson <<< map encodeNaiveDateTime) record.reminders)
+    ]
+"
+    ));
+    assert!(output.contains("    v3 <- (traverse decodeNaiveDateTime <=< decodeJson) =<< object .: \"reminders\"\n"));
+    // The helpers share their functions, which are only declared once.
+    assert_eq!(output.matches("encodeDate :: Date -> Json").count(), 1);
+    assert!(output.contains(
+        "encodeDateTime :: DateTime -> Json
+encodeDateTime (DateTime.DateTime d t) = fromString (printDate d <> \"T\" <> printTime t <> \"Z\")
+"
+    ));
+    assert!(output.contains("import Data.Time.Duration (\n  Minutes(..)\n)\n"));
+}
+
+#[test]
+#[cfg(not(feature = "rust_decimal_float"))]
+fn decimal_codecs() {
+    #[derive(AsPursType)]
+    struct Price {
+        amount: rust_decimal::Decimal,
+        discounts: Vec<rust_decimal::Decimal>,
+    }
+
+    let module = purs_module!("Prices".to_string() ; Price).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("data Price = Price { amount :: String, discounts :: Array String }"));
+    assert!(!output.contains("encodeDecimal"));
+
+    let module = purs_module!("Prices".to_string() ; Price)
+        .with_decimals(Decimals::Decimal)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Decimal as Decimal\n"));
+    assert!(output.contains("data Price = Price { amount :: Decimal, discounts :: Array Decimal }"));
+    assert!(output.contains("    , Tuple \"discounts\" ((encodeJson <<< map encodeDecimal) record.discounts)\n"));
+    assert!(output.ends_with(
+        "
+encodeDecimal :: Decimal -> Json
+encodeDecimal = fromString <<< Decimal.toString
+
+decodeDecimal :: Json -> Either JsonDecodeError Decimal
+decodeDecimal json = note (UnexpectedValue json) $ case toString json of
+  Just string -> Decimal.fromString string
+  Nothing -> Decimal.fromNumber <$> toNumber json
+"
+    ));
+}
+
+#[test]
+#[cfg(feature = "rust_decimal_float")]
+fn float_decimal_codecs() {
+    #[derive(AsPursType, Serialize)]
+    struct Price {
+        amount: rust_decimal::Decimal,
+    }
+
+    let price = Price {
+        amount: rust_decimal::Decimal::new(125, 2),
+    };
+    assert_eq!(serde_json::to_string(&price).unwrap(), r#"{"amount":1.25}"#);
+
+    let module = purs_module!("Prices".to_string() ; Price)
+        .with_decimals(Decimals::Decimal)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("data Price = Price { amount :: Number }"));
+    assert!(!output.contains("encodeDecimal"));
+}
+
+#[test]
+fn ordered_map_codecs() {
+    #[derive(AsPursType)]
+    struct Menu {
+        courses: indexmap::IndexMap<String, Vec<String>>,
+        prices: indexmap::IndexMap<u8, i32>,
+    }
+
+    let module = purs_module!("Menus".to_string() ; Menu).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "data Menu = Menu { courses :: OrderedMap String (Array String), prices :: OrderedMap Int Int }"
+    ));
+    assert!(output.contains(
+        "    [ Tuple \"courses\" ((encodeOrderedMap encodeJson encodeJson) record.courses)\n"
+    ));
+    assert!(output.contains(
+        "    v1 <- (decodeOrderedMap decodeJson decodeJson) =<< object .: \"prices\"\n"
+    ));
+    assert!(output.contains("-- | The entries of a map, in order.\ntype OrderedMap k v = Array (Tuple k v)\n"));
+    assert!(output.contains("\nencodeMapKey :: Json -> String\n"));
+    assert!(output.contains("\n  traverse decodeEntry entries\n"));
+    assert!(!output.contains("Data.Map"));
+}
+
+#[test]
+fn either_codecs() {
+    #[derive(AsPursType)]
+    struct Lookup {
+        found: either::Either<String, i32>,
+        attempts: Vec<Result<i32, String>>,
+    }
+
+    let module = purs_module!("Lookups".to_string() ; Lookup).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Either (\n  Either(..),\n  either\n)\n"));
+    assert!(!output.contains("PRIM"));
+    assert!(output.contains(
+        "data Lookup = Lookup { found :: Either String Int, attempts :: Array (Either String Int) }"
+    ));
+    assert!(output.contains("    [ Tuple \"found\" ((encodeEither encodeJson encodeJson) record.found)\n"));
+    assert!(output.contains(
+        "    , Tuple \"attempts\" ((encodeJson <<< map (encodeResult encodeJson encodeJson)) record.attempts)\n"
+    ));
+    assert!(output.contains("  Just [Tuple \"Left\" value] -> Left <$> decodeLeft value\n"));
+}
+
+#[test]
+fn renamed_variant_codecs() {
+    #[derive(AsPursType, Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Status {
+        InProgress,
+        #[serde(rename = "done!")]
+        Done,
+        FailedWith(String),
+    }
+
+    assert_eq!(serde_json::to_string(&Status::InProgress).unwrap(), r#""in_progress""#);
+    assert_eq!(serde_json::to_string(&Status::Done).unwrap(), r#""done!""#);
+    assert_eq!(
+        serde_json::to_string(&Status::FailedWith("oops".to_string())).unwrap(),
+        r#"{"failed_with":"oops"}"#
+    );
+
+    let module = purs_module!("Statuses".to_string() ; Status).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("data Status = InProgress | Done | FailedWith String\n"));
+    assert!(output.contains(
+        "  encodeJson value = case value of
+    InProgress -> fromString \"in_progress\"
+    Done -> fromString \"done!\"
+    (FailedWith a0) -> fromObject (singleton \"failed_with\" (encodeJson a0))
+"
+    ));
+    assert!(output.contains(
+        "  decodeJson json = case toString json of
+    Just \"in_progress\" -> Right InProgress
+    Just \"done!\" -> Right Done
+    _ -> case map toUnfoldable (toObject json) of
+      Just [Tuple \"failed_with\" value] -> "
+    ));
+}
+
+#[test]
+fn records_declared_as_type_synonyms_are_inlined() {
+    use std::collections::HashMap;
+
+    #[derive(AsPursType)]
+    #[purs(representation = "type", label_case = "camelCase")]
+    struct Stock {
+        item_count: i32,
+        by_id: HashMap<i32, String>,
+    }
+
+    #[derive(AsPursType)]
+    struct Warehouse {
+        name: String,
+        stock: Stock,
+    }
+
+    let module = purs_module!("Warehouses".to_string() ; Stock, Warehouse).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("type Stock = { itemCount :: Int, byId :: Map Int String }\n"));
+    assert!(output.contains(
+        "    , Tuple \"stock\" ((\\record -> fromObject (fromFoldable [Tuple \"item_count\" (encodeJson record.itemCount), Tuple \"by_id\" ((encodeMap encodeJson encodeJson) record.byId)])) record.stock)
+"
+    ));
+    assert!(output.contains(
+        "    v1 <- (\\json -> decodeJson json >>= \\object -> { itemCount: _, byId: _ } <$> (object .: \"item_count\") <*> ((decodeMap decodeJson decodeJson) =<< object .: \"by_id\")) =<< object .: \"stock\"
+"
+    ));
+    assert!(!output.contains("instance encodeJsonStock"));
+}
+
+#[test]
+fn containers_of_inlined_records_import_their_combinators() {
+    #[derive(AsPursType)]
+    #[purs(representation = "type", label_case = "camelCase")]
+    struct Inner {
+        item_count: i32,
+    }
+
+    #[derive(AsPursType)]
+    struct Outer {
+        a: Option<Inner>,
+        b: Vec<Inner>,
+    }
+
+    let module = purs_module!("Outers".to_string() ; Inner, Outer).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("(maybe jsonNull (\\record -> "));
+    assert!(output.contains("traverse (\\json -> "));
+    assert!(output.contains("import Data.Maybe (\n  Maybe(..),\n  maybe\n)\n"));
+    assert!(output.contains("import Data.Traversable (\n  traverse\n)\n"));
+}
+
+#[test]
+fn recursive_records_declared_as_type_synonyms_are_not_expanded_forever() {
+    #[derive(AsPursType)]
+    #[purs(representation = "type")]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    #[derive(AsPursType)]
+    struct Tree {
+        root: Node,
+    }
+
+    let module = purs_module!("Trees".to_string() ; Node, Tree).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("type Node = { children :: Array Node }\n"));
+    assert!(output.contains("data Tree = Tree { root :: Node }\n"));
+}
+
+#[test]
+fn helpers_are_only_emitted_for_codecs_using_them() {
+    use std::collections::HashMap;
+
+    #[derive(AsPursType)]
+    #[purs(representation = "type")]
+    struct Stock {
+        by_id: HashMap<i32, String>,
+    }
+
+    let module = purs_module!("Stocks".to_string() ; Stock).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("type Stock = { by_id :: Map Int String }\n"));
+    assert!(!output.contains("encodeMap"));
+}
+
+#[test]
+fn big_integer_digit_codecs() {
+    use num_bigint::{BigInt, BigUint};
+
+    #[derive(AsPursType, Serialize)]
+    struct Balance {
+        supply: BigUint,
+        delta: BigInt,
+        limit: Option<BigInt>,
+    }
+
+    let balance = Balance {
+        supply: BigUint::from(1u64 << 32),
+        delta: BigInt::from(-5),
+        limit: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&balance).unwrap(),
+        r#"{"supply":[0,1],"delta":[-1,[5]],"limit":null}"#
+    );
+
+    let digits = purs_module!("Balances".to_string() ; Balance).with_codecs();
+    let output = format!("{}", &digits);
+    assert!(output.contains(
+        "data Balance = Balance { supply :: Array Number, delta :: Tuple Int (Array Number), limit :: Maybe (Tuple Int (Array Number)) }"
+    ));
+    assert!(!output.contains("Digits"));
+
+    let module = purs_module!("Balances".to_string() ; Balance)
+        .with_big_integers(BigIntegers::BigInt)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import JS.BigInt (\n  BigInt\n)\n"));
+    assert!(output.contains(
+        "data Balance = Balance { supply :: BigInt, delta :: BigInt, limit :: Maybe BigInt }"
+    ));
+    assert!(output.contains(
+        "    [ Tuple \"supply\" (encodeBigUintDigits record.supply)
+    , Tuple \"delta\" (encodeBigIntDigits record.delta)
+    , Tuple \"limit\" ((maybe jsonNull encodeBigIntDigits) record.limit)
+"
+    ));
+    assert!(output.contains(
+        "    v0 <- decodeBigUintDigits =<< object .: \"supply\"
+    v1 <- decodeBigIntDigits =<< object .: \"delta\"
+    v2 <- traverse decodeBigIntDigits =<< object .:? \"limit\"
+"
+    ));
+    assert!(output.contains("\ndecodeBigUintDigits :: Json -> Either JsonDecodeError BigInt\n"));
+    assert!(output.contains("\nencodeBigIntDigits :: BigInt -> Json\n"));
+    assert!(!output.contains("encodeBigInt ::"));
+}
+
+#[test]
+fn fields_named_like_keywords_are_not_punned() {
+    #[derive(AsPursType, Serialize)]
+    struct Message {
+        data: String,
+        #[serde(rename = "type")]
+        kind: String,
+    }
+
+    let module = purs_module!("Messages".to_string() ; Message).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "    v0 <- object .: \"data\"
+    v1 <- object .: \"type\"
+    pure (Message { data: v0, type: v1 })
+"
+    ));
+}
@@ -0,0 +1,131 @@
+#![allow(dead_code, deprecated)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+
+use purescript_waterslide::*;
+
+macro_rules! assert_derives_to {
+    ($rust_type:ty, $ps_type:expr) => {
+        assert_eq!(
+            &format!("{}", <$rust_type as AsPursType>::as_purs_type()),
+            $ps_type
+        )
+    }
+}
+
+#[test]
+fn documented_struct() {
+    /// A person.
+    ///
+    /// Persons have names.
+    #[derive(AsPursType)]
+    struct Person {
+        /// Age in years
+        age: i32,
+        name: String,
+    }
+
+    assert_eq!(
+        Person::as_purs_type().attributes().doc,
+        Some("A person.\n\nPersons have names.".to_string())
+    );
+
+    assert_derives_to!(
+        Person,
+        "-- | A person.
+-- |
+-- | Persons have names.
+data Person = Person
+  { -- | Age in years
+    age :: Int
+  , name :: String
+  }"
+    );
+}
+
+#[test]
+fn documented_enum() {
+    #[derive(AsPursType)]
+    enum Fruit {
+        /// Yellow and curved
+        Banana,
+        Pear(u8),
+        /// Red or green
+        /// but never blue
+        Apple,
+    }
+
+    assert_derives_to!(
+        Fruit,
+        "data Fruit
+  -- | Yellow and curved
+  = Banana
+  | Pear Int
+  -- | Red or green
+  -- | but never blue
+  | Apple"
+    );
+}
+
+#[test]
+fn deprecated_types() {
+    /// An old type
+    #[derive(AsPursType)]
+    #[deprecated(since = "0.2.0", note = "use NewId instead")]
+    struct OldId(i32);
+
+    #[derive(AsPursType)]
+    #[deprecated]
+    struct Legacy;
+
+    #[derive(AsPursType)]
+    enum Shape {
+        #[deprecated = "squares are out of fashion"]
+        Square,
+        Circle,
+    }
+
+    assert_derives_to!(
+        OldId,
+        "-- | An old type
+-- |
+-- | Deprecated: use NewId instead
+data OldId = OldId Int"
+    );
+    assert_derives_to!(Legacy, "-- | Deprecated\ndata Legacy = Legacy");
+    assert_derives_to!(
+        Shape,
+        "data Shape
+  -- | Deprecated: squares are out of fashion
+  = Square
+  | Circle"
+    );
+}
+
+#[test]
+fn documented_module() {
+    /// A color
+    #[derive(AsPursType)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    let module = purs_module!("Colors".to_string() ; Color);
+    assert_eq!(
+        &format!("{}", &module),
+        "module Colors where
+
+import Data.Generic (
+  class Generic
+)
+
+-- | A color
+data Color = Red | Green
+
+derive instance genericColor :: Generic Color
+"
+    );
+}
@@ -30,28 +30,28 @@ fn plain_old_enum() {
         GoodBoy::as_purs_type(),
         // data GoodBoy = Doggo | Pupper | Shibe
         PursType::Enum(
-            PursConstructor {
-                name: "GoodBoy".to_string(),
-                module: None,
-                parameters: vec![],
-            },
+            PursConstructor::new("GoodBoy", None, vec![]),
             vec![
-                PursConstructor {
-                    module: None,
+                PursVariant {
                     name: "Doggo".to_string(),
-                    parameters: vec![],
+                    constructor: PursConstructor::new("Doggo", None, vec![]),
+                    discriminant: None,
+                    doc: None,
                 },
-                PursConstructor {
-                    module: None,
+                PursVariant {
                     name: "Pupper".to_string(),
-                    parameters: vec![],
+                    constructor: PursConstructor::new("Pupper", None, vec![]),
+                    discriminant: None,
+                    doc: None,
                 },
-                PursConstructor {
-                    module: None,
+                PursVariant {
                     name: "Shibe".to_string(),
-                    parameters: vec![],
+                    constructor: PursConstructor::new("Shibe", None, vec![]),
+                    discriminant: None,
+                    doc: None,
                 },
-            ]
+            ],
+            PursAttributes::default(),
         )
     );
 
@@ -141,3 +141,93 @@ fn simple_generic_enum() {
         "data Choice l r = Left l | Right r"
     );
 }
+
+#[test]
+fn enum_with_discriminants() {
+    #[derive(AsPursType)]
+    #[repr(u8)]
+    #[purs(int_enum)]
+    enum Priority {
+        Low = 1,
+        Medium,
+        High = 10,
+    }
+
+    match Priority::as_purs_type() {
+        PursType::Enum(_, constructors, _) => assert_eq!(
+            constructors.iter().map(|c| c.discriminant).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(10)]
+        ),
+        other => panic!("Expected an enum, got {:?}", other),
+    }
+
+    assert_derives_to!(Priority, "data Priority = Low | Medium | High");
+}
+
+#[test]
+fn integer_repr_enum_without_int_enum_attribute() {
+    #[derive(AsPursType)]
+    #[repr(u8)]
+    enum Flavor {
+        Sweet = 1,
+        Sour = 2,
+    }
+
+    match Flavor::as_purs_type() {
+        PursType::Enum(_, constructors, _) => assert_eq!(
+            constructors.iter().map(|c| c.discriminant).collect::<Vec<_>>(),
+            vec![None, None]
+        ),
+        other => panic!("Expected an enum, got {:?}", other),
+    }
+}
+
+#[test]
+fn empty_enum() {
+    #[derive(AsPursType)]
+    enum Never {}
+
+    assert_derives_to!(Never, "data Never");
+}
+
+#[test]
+fn enum_with_negative_and_constant_discriminants() {
+    const BASE: i64 = 1 << 20;
+
+    #[derive(AsPursType)]
+    #[repr(i64)]
+    #[purs(int_enum)]
+    enum Offset {
+        Before = -2147483648,
+        Backward = -1,
+        Forward = BASE,
+        Further,
+    }
+
+    match Offset::as_purs_type() {
+        PursType::Enum(_, constructors, _) => assert_eq!(
+            constructors.iter().map(|c| c.discriminant).collect::<Vec<_>>(),
+            vec![Some(-(1 << 31)), Some(-1), Some(1 << 20), Some((1 << 20) + 1)]
+        ),
+        other => panic!("Expected an enum, got {:?}", other),
+    }
+}
+
+#[test]
+fn integer_repr_enum_without_explicit_discriminants() {
+    #[derive(AsPursType)]
+    #[repr(i32)]
+    #[purs(int_enum)]
+    enum Direction {
+        North,
+        South,
+    }
+
+    match Direction::as_purs_type() {
+        PursType::Enum(_, constructors, _) => assert_eq!(
+            constructors.iter().map(|c| c.discriminant).collect::<Vec<_>>(),
+            vec![Some(0), Some(1)]
+        ),
+        other => panic!("Expected an enum, got {:?}", other),
+    }
+}
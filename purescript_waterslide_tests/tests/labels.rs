@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+#[macro_use]
+extern crate serde_derive;
+
+use purescript_waterslide::*;
+
+macro_rules! assert_derives_to {
+    ($rust_type:ty, $ps_type:expr) => {
+        assert_eq!(
+            &format!("{}", <$rust_type as AsPursType>::as_purs_type()),
+            $ps_type
+        )
+    }
+}
+
+#[test]
+fn label_case_conversion() {
+    assert_eq!(LabelCase::CamelCase.convert("page_num"), "pageNum");
+    assert_eq!(LabelCase::CamelCase.convert("PageNum"), "pageNum");
+    assert_eq!(LabelCase::CamelCase.convert("total"), "total");
+    assert_eq!(LabelCase::SnakeCase.convert("pageNum"), "page_num");
+    assert_eq!(LabelCase::SnakeCase.convert("page-num"), "page_num");
+    assert_eq!(LabelCase::Preserve.convert("page_num"), "page_num");
+}
+
+#[test]
+fn container_label_case() {
+    #[derive(AsPursType)]
+    #[purs(label_case = "camelCase")]
+    struct Page {
+        page_num: i32,
+        items_per_page: i32,
+    }
+
+    assert_derives_to!(
+        Page,
+        "data Page = Page { pageNum :: Int, itemsPerPage :: Int }"
+    );
+}
+
+#[test]
+fn serde_renames_are_field_names() {
+    #[derive(AsPursType, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Page {
+        page_num: i32,
+        #[serde(rename = "size")]
+        items_per_page: i32,
+        #[serde(rename(serialize = "ttl", deserialize = "ttl"))]
+        time_to_live: i32,
+    }
+
+    assert_derives_to!(
+        Page,
+        "data Page = Page { pageNum :: Int, size :: Int, ttl :: Int }"
+    );
+}
+
+#[test]
+fn module_label_case_with_codecs() {
+    #[derive(AsPursType)]
+    struct Page {
+        page_num: i32,
+        next_page: Option<i32>,
+    }
+
+    #[derive(AsPursType)]
+    #[purs(label_case = "preserve")]
+    struct Legacy {
+        old_field: bool,
+    }
+
+    let module = purs_module!("Pages".to_string() ; Page, Legacy)
+        .with_label_case(LabelCase::CamelCase)
+        .with_codecs();
+    let output = format!("{}", &module);
+
+    assert!(output.contains("data Page = Page { pageNum :: Int, nextPage :: Maybe Int }"));
+    assert!(output.contains("data Legacy = Legacy { old_field :: Boolean }"));
+    assert!(output.contains(
+        "  encodeJson (Page record) = fromObject (fromFoldable
+    [ Tuple \"page_num\" (encodeJson record.pageNum)
+    , Tuple \"next_page\" (encodeJson record.nextPage)
+    ]
+  )
+"
+    ));
+    assert!(output.contains(
+        "    v0 <- object .: \"page_num\"
+    v1 <- object .:? \"next_page\"
+    pure (Page { pageNum: v0, nextPage: v1 })
+"
+    ));
+}
+
+#[test]
+fn labels_that_are_not_identifiers_are_quoted() {
+    #[derive(AsPursType, Serialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Page {
+        page_num: i32,
+        #[serde(rename = "@type")]
+        kind: String,
+    }
+
+    assert_derives_to!(
+        Page,
+        "data Page = Page { \"page-num\" :: Int, \"@type\" :: String }"
+    );
+
+    let module = purs_module!("Pages".to_string() ; Page)
+        .with_codecs()
+        .with_lenses();
+    let output = format!("{}", &module);
+
+    assert!(output.contains(
+        "  encodeJson (Page record) = fromObject (fromFoldable
+    [ Tuple \"page-num\" (encodeJson record.\"page-num\")
+    , Tuple \"@type\" (encodeJson record.\"@type\")
+    ]
+  )
+"
+    ));
+    assert!(output.contains(
+        "    v0 <- object .: \"page-num\"
+    v1 <- object .: \"@type\"
+    pure (Page { \"page-num\": v0, \"@type\": v1 })
+"
+    ));
+    assert!(output.contains(
+        "_pageNum :: forall a b r. Lens { \"page-num\" :: a | r } { \"page-num\" :: b | r } a b
+_pageNum = prop (Proxy :: Proxy \"page-num\")
+"
+    ));
+    assert!(output.contains(
+        "_type :: forall a b r. Lens { \"@type\" :: a | r } { \"@type\" :: b | r } a b
+_type = prop (Proxy :: Proxy \"@type\")
+"
+    ));
+}
@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+extern crate void;
+
+use void::Void;
+use purescript_waterslide::*;
+
+#[test]
+fn module_with_lenses() {
+    #[derive(AsPursType)]
+    enum Page<T> {
+        NonEmpty(Vec<T>),
+        Range(i32, i32),
+        OOB,
+    }
+
+    #[derive(AsPursType)]
+    struct Paginated<T> {
+        page_num: i32,
+        contents: Page<T>,
+    }
+
+    #[derive(AsPursType)]
+    struct Summary {
+        page_num: i32,
+        total: i32,
+    }
+
+    #[derive(AsPursType)]
+    struct Email(String);
+
+    let module = purs_module!("Pagination".to_string() ;
+                              Page<Void>, Paginated<Void>, Summary, Email)
+        .with_lenses();
+    assert_eq!(
+        &format!("{}", &module),
+        "module Pagination where
+
+import Prelude
+import Data.Generic (
+  class Generic
+)
+import Data.Lens (
+  Iso',
+  Lens,
+  Prism',
+  iso,
+  prism'
+)
+import Data.Lens.Record (
+  prop
+)
+import Data.Maybe (
+  Maybe(..)
+)
+import Type.Proxy (
+  Proxy(..)
+)
+
+data Page t = NonEmpty (Array t) | Range Int Int | OOB
+
+derive instance genericPage :: Generic Page
+
+_NonEmpty :: forall t. Prism' (Page t) (Array t)
+_NonEmpty = prism' NonEmpty $ case _ of
+  NonEmpty a -> Just a
+  _ -> Nothing
+
+_Range :: forall t. Prism' (Page t) { a :: Int, b :: Int }
+_Range = prism' (\\{ a, b } -> Range a b) $ case _ of
+  Range a b -> Just { a, b }
+  _ -> Nothing
+
+_OOB :: forall t. Prism' (Page t) Unit
+_OOB = prism' (const OOB) $ case _ of
+  OOB -> Just unit
+  _ -> Nothing
+
+data Paginated t = Paginated { page_num :: Int, contents :: Page t }
+
+derive instance genericPaginated :: Generic Paginated
+
+_Paginated :: forall t. Iso' (Paginated t) { page_num :: Int, contents :: Page t }
+_Paginated = iso (\\(Paginated r) -> r) Paginated
+
+data Summary = Summary { page_num :: Int, total :: Int }
+
+derive instance genericSummary :: Generic Summary
+
+_Summary :: Iso' Summary { page_num :: Int, total :: Int }
+_Summary = iso (\\(Summary r) -> r) Summary
+
+data Email = Email String
+
+derive instance genericEmail :: Generic Email
+
+_Email :: Iso' Email String
+_Email = iso (\\(Email a) -> a) Email
+
+_contents :: forall a b r. Lens { contents :: a | r } { contents :: b | r } a b
+_contents = prop (Proxy :: Proxy \"contents\")
+
+_page_num :: forall a b r. Lens { page_num :: a | r } { page_num :: b | r } a b
+_page_num = prop (Proxy :: Proxy \"page_num\")
+
+_total :: forall a b r. Lens { total :: a | r } { total :: b | r } a b
+_total = prop (Proxy :: Proxy \"total\")
+"
+    );
+}
+
+#[test]
+fn single_constructor_prism_is_exhaustive() {
+    #[derive(AsPursType)]
+    enum Wrapper {
+        Only(bool),
+    }
+
+    let module = purs_module!("Wrappers".to_string() ; Wrapper).with_lenses();
+    assert!(format!("{}", &module).contains(
+        "_Only :: Prism' Wrapper Boolean
+_Only = prism' Only $ case _ of
+  Only a -> Just a
+"
+    ));
+}
+
+#[test]
+fn colliding_optics_are_qualified() {
+    #[derive(AsPursType)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(AsPursType)]
+    enum Actor {
+        User(User),
+        Bot,
+    }
+
+    #[derive(AsPursType)]
+    #[allow(non_snake_case)]
+    struct Login {
+        User: User,
+    }
+
+    let module = purs_module!("Actors".to_string() ; User, Actor, Login).with_lenses();
+    let output = format!("{}", &module);
+    assert!(output.contains("_User :: Iso' User { name :: String }\n"));
+    assert!(output.contains(
+        "_ActorUser :: Prism' Actor User
+_ActorUser = prism' User $ case _ of
+  User a -> Just a
+  _ -> Nothing
+"
+    ));
+    assert!(output.contains("_Bot :: Prism' Actor Unit\n"));
+    assert!(output.contains(
+        "_fieldUser :: forall a b r. Lens { \"User\" :: a | r } { \"User\" :: b | r } a b
+_fieldUser = prop (Proxy :: Proxy \"User\")
+"
+    ));
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+extern crate purescript_waterslide;
+
+use purescript_waterslide::*;
+
+struct SessionToken(Vec<u8>);
+purs_primitive!(SessionToken => "String");
+
+struct Instant(u64);
+purs_primitive!(Instant => "Instant" in "Data.DateTime.Instant");
+
+struct UserId(u64);
+purs_newtype!(UserId(i32));
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+purs_record!(Point { x: f64, y: f64 });
+
+enum Shape {
+    Circle(f64),
+    Rectangle(f64, f64),
+    Empty,
+}
+purs_enum!(Shape {
+    Circle(f64),
+    Rectangle(f64, f64),
+    Empty,
+});
+
+struct Drawing;
+purs_record!(Drawing {
+    author: UserId,
+    token: SessionToken,
+    created_at: Instant,
+    origin: Point,
+    shapes: Vec<Shape>,
+});
+
+#[test]
+fn primitives() {
+    assert_eq!(
+        SessionToken::as_purs_constructor(),
+        PursConstructor::new("String", None, vec![])
+    );
+    assert_eq!(
+        Instant::as_purs_constructor(),
+        PursConstructor::new("Instant", Some("Data.DateTime.Instant"), vec![])
+    );
+}
+
+#[test]
+fn declarations() {
+    assert_eq!(
+        &format!("{}", UserId::as_purs_type()),
+        "newtype UserId = UserId Int"
+    );
+    assert_eq!(
+        &format!("{}", Point::as_purs_type()),
+        "data Point = Point { x :: Number, y :: Number }"
+    );
+    assert_eq!(
+        &format!("{}", Shape::as_purs_type()),
+        "data Shape = Circle Number | Rectangle Number Number | Empty"
+    );
+}
+
+#[test]
+fn module_with_hand_written_impls() {
+    let module = purs_module!("Drawings".to_string() ; UserId, Point, Shape, Drawing);
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.DateTime.Instant (\n  Instant\n)\n"));
+    assert!(output.contains("import Data.Newtype (\n  class Newtype\n)\n"));
+    assert!(output.contains("derive instance newtypeUserId :: Newtype UserId _\n"));
+    assert!(output.contains(
+        "data Drawing = Drawing { author :: UserId, token :: String, created_at :: Instant, origin :: Point, shapes :: Array Shape }"
+    ));
+}
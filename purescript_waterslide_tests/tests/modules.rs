@@ -3,6 +3,13 @@
 #[macro_use]
 extern crate purescript_waterslide_derive;
 extern crate purescript_waterslide;
+extern crate chrono;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate semver;
+extern crate time;
+extern crate url;
 extern crate void;
 
 use void::*;
@@ -37,7 +44,7 @@ fn module_format() {
         "module Fruits where
 
 import Data.Generic (
-class Generic
+  class Generic
 )
 
 data Currency = Coins | Credits | Abolished
@@ -48,7 +55,7 @@ data Color = Red Int | Green Int | Blue (Array Int)
 
 derive instance genericColor :: Generic Color
 
-data Fruit = Fruit { color :: Color, price :: Int, currency :: Currency }
+data Fruit = Fruit { color :: Color, price :: Number, currency :: Currency }
 
 derive instance genericFruit :: Generic Fruit
 "
@@ -65,7 +72,7 @@ fn module_with_generics() {
 
     #[derive(AsPursType)]
     struct Paginated<T, META> {
-        page_num: u32,
+        page_num: i32,
         contents: Page<T>,
         metadata: META,
     }
@@ -86,7 +93,7 @@ fn module_with_generics() {
         "module Pagination where
 
 import Data.Generic (
-class Generic
+  class Generic
 )
 
 data Paginated t meta = Paginated { page_num :: Int, contents :: Page t, metadata :: meta }
@@ -103,3 +110,423 @@ derive instance genericSomethingElse :: Generic SomethingElse
 "
     );
 }
+
+#[test]
+fn module_with_record_representations() {
+    #[derive(AsPursType)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(AsPursType)]
+    #[purs(representation = "type")]
+    struct Size<T> {
+        width: T,
+        height: T,
+    }
+
+    #[derive(AsPursType)]
+    #[purs(representation = "data")]
+    struct Legacy {
+        old: bool,
+    }
+
+    let module = purs_module!("Geometry".to_string() ; Point, Size<Void>, Legacy)
+        .with_record_representation(RecordRepresentation::Newtype)
+        .with_codecs();
+    let output = format!("{}", &module);
+
+    assert!(output.contains(
+        "import Data.Newtype (
+  class Newtype
+)
+"
+    ));
+    assert!(output.contains(
+        "newtype Point = Point { x :: Int, y :: Int }
+
+derive instance genericPoint :: Generic Point
+
+derive instance newtypePoint :: Newtype Point _
+
+instance encodeJsonPoint :: EncodeJson Point where
+"
+    ));
+    assert!(output.contains(
+        "type Size t = { width :: t, height :: t }
+
+data Legacy = Legacy { old :: Boolean }
+"
+    ));
+    assert!(!output.contains("Generic Size"));
+    assert!(!output.contains("JsonSize"));
+}
+
+#[test]
+fn module_with_sets() {
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(AsPursType)]
+    struct Permissions {
+        roles: HashSet<String>,
+        scopes: Vec<BTreeSet<u8>>,
+    }
+
+    let module = purs_module!("Permissions".to_string() ; Permissions);
+    assert_eq!(
+        &format!("{}", &module),
+        "module Permissions where
+
+import Data.Generic (
+  class Generic
+)
+import Data.Set (
+  Set
+)
+
+data Permissions = Permissions { roles :: Set String, scopes :: Array (Set Int) }
+
+derive instance genericPermissions :: Generic Permissions
+"
+    );
+
+    let module = purs_module!("Permissions".to_string() ; Permissions).with_sets_as_arrays();
+    assert_eq!(
+        &format!("{}", &module),
+        "module Permissions where
+
+import Data.Generic (
+  class Generic
+)
+
+data Permissions = Permissions { roles :: Array String, scopes :: Array (Array Int) }
+
+derive instance genericPermissions :: Generic Permissions
+"
+    );
+}
+
+#[test]
+fn module_with_tuples() {
+    #[derive(AsPursType)]
+    struct Measures {
+        point: (f64, f64, f64),
+        row: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+        nothing: (),
+    }
+
+    let module = purs_module!("Measures".to_string() ; Measures);
+    assert_eq!(
+        &format!("{}", &module),
+        "module Measures where
+
+import Data.Generic (
+  class Generic
+)
+import Data.Tuple (
+  Tuple
+)
+import Data.Tuple.Nested (
+  Tuple3
+)
+import Prelude (
+  Unit
+)
+
+data Measures = Measures { point :: Tuple3 Number Number Number, row :: Tuple11 Int Int Int Int Int Int Int Int Int Int Int, nothing :: Unit }
+
+derive instance genericMeasures :: Generic Measures
+
+type Tuple11 a b c d e f g h i j k = Tuple a (Tuple b (Tuple c (Tuple d (Tuple e (Tuple f (Tuple g (Tuple h (Tuple i (Tuple j (Tuple k Unit))))))))))
+"
+    );
+
+    let module = purs_module!("Measures".to_string() ; Measures).with_nested_tuples();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Tuple.Nested (\n  T3,\n  T11\n)\n"));
+    assert!(output.contains(
+        "data Measures = Measures { point :: T3 Number Number Number, row :: T11 Int Int Int Int Int Int Int Int Int Int Int, nothing :: Unit }"
+    ));
+    assert!(!output.contains("type "));
+}
+
+#[test]
+fn module_with_wide_integers() {
+    #[derive(AsPursType)]
+    struct Account {
+        id: u64,
+        balance: i128,
+        count: i32,
+    }
+
+    let module = purs_module!("Accounts".to_string() ; Account);
+    assert!(format!("{}", &module).contains(
+        "data Account = Account { id :: Number, balance :: Number, count :: Int }"
+    ));
+
+    let module = purs_module!("Accounts".to_string() ; Account)
+        .with_wide_integers(WideIntegers::String);
+    assert!(format!("{}", &module).contains(
+        "data Account = Account { id :: String, balance :: String, count :: Int }"
+    ));
+
+    let module = purs_module!("Accounts".to_string() ; Account)
+        .with_wide_integers(WideIntegers::BigInt);
+    let output = format!("{}", &module);
+    assert!(output.contains("import JS.BigInt (\n  BigInt\n)\n"));
+    assert!(output.contains(
+        "data Account = Account { id :: BigInt, balance :: BigInt, count :: Int }"
+    ));
+}
+
+#[test]
+fn module_with_dates() {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    #[derive(AsPursType)]
+    struct Meeting {
+        day: NaiveDate,
+        start: NaiveTime,
+        created_at: DateTime<Utc>,
+        scheduled_at: Option<DateTime<FixedOffset>>,
+        reminders: Vec<NaiveDateTime>,
+    }
+
+    let module = purs_module!("Meetings".to_string() ; Meeting);
+    assert!(format!("{}", &module).contains(
+        "data Meeting = Meeting { day :: String, start :: String, created_at :: String, scheduled_at :: Maybe String, reminders :: Array String }"
+    ));
+
+    let module = purs_module!("Meetings".to_string() ; Meeting).with_dates(Dates::DateTime);
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Date (\n  Date\n)\n"));
+    assert!(output.contains("import Data.DateTime (\n  DateTime\n)\n"));
+    assert!(output.contains("import Data.Time (\n  Time\n)\n"));
+    assert!(!output.contains("PRIM"));
+    assert!(output.contains(
+        "data Meeting = Meeting { day :: Date, start :: Time, created_at :: DateTime, scheduled_at :: Maybe DateTime, reminders :: Array NaiveDateTime }"
+    ));
+    assert!(output.ends_with(
+        "-- | A date and time without an offset.\ntype NaiveDateTime = DateTime\n"
+    ));
+}
+
+#[test]
+fn module_with_json_values() {
+    #[derive(AsPursType)]
+    struct Event {
+        kind: String,
+        payload: serde_json::Value,
+        metadata: Option<Box<serde_json::value::RawValue>>,
+    }
+
+    let module = purs_module!("Events".to_string() ; Event);
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Argonaut.Core (\n  Json\n)\n"));
+    assert!(output.contains(
+        "data Event = Event { kind :: String, payload :: Json, metadata :: Maybe Json }"
+    ));
+
+    let module = purs_module!("Events".to_string() ; Event).with_json_values(JsonValues::Foreign);
+    let output = format!("{}", &module);
+    assert!(output.contains("import Foreign (\n  Foreign\n)\n"));
+    assert!(!output.contains("Data.Argonaut.Core"));
+    assert!(output.contains(
+        "data Event = Event { kind :: String, payload :: Foreign, metadata :: Maybe Foreign }"
+    ));
+}
+
+#[test]
+fn module_with_time_dates() {
+    #[derive(AsPursType, Serialize)]
+    struct Booking {
+        day: time::Date,
+        opens: time::Time,
+        #[serde(with = "time::serde::rfc3339")]
+        at: time::OffsetDateTime,
+        #[serde(with = "time::serde::rfc3339::option")]
+        cancelled_at: Option<time::OffsetDateTime>,
+        created_at: time::OffsetDateTime,
+        local: time::PrimitiveDateTime,
+    }
+
+    let epoch = time::OffsetDateTime::UNIX_EPOCH;
+    let booking = Booking {
+        day: epoch.date(),
+        opens: epoch.time(),
+        at: epoch,
+        cancelled_at: None,
+        created_at: epoch,
+        local: time::PrimitiveDateTime::new(epoch.date(), epoch.time()),
+    };
+    let json = serde_json::to_value(&booking).unwrap();
+    assert_eq!(json["day"], "1970-01-01");
+    assert_eq!(json["opens"], "00:00:00.0");
+    assert_eq!(json["at"], "1970-01-01T00:00:00Z");
+    assert_eq!(json["created_at"], "1970-01-01 00:00:00.0 +00:00:00");
+    assert_eq!(json["local"], "1970-01-01 00:00:00.0");
+
+    let module = purs_module!("Bookings".to_string() ; Booking).with_dates(Dates::DateTime);
+    assert!(format!("{}", &module).contains(
+        "data Booking = Booking { day :: Date, opens :: Time, at :: DateTime, cancelled_at :: Maybe DateTime, created_at :: String, local :: String }"
+    ));
+}
+
+#[test]
+fn declared_types_named_like_local_synonyms() {
+    #[derive(AsPursType)]
+    struct NaiveDateTime {
+        seconds: i32,
+    }
+
+    #[derive(AsPursType)]
+    struct OrderedMap {
+        keys: Vec<String>,
+    }
+
+    #[derive(AsPursType)]
+    struct Log {
+        started: NaiveDateTime,
+        index: OrderedMap,
+    }
+
+    let module = purs_module!("Logs".to_string() ; NaiveDateTime, OrderedMap, Log)
+        .with_dates(Dates::DateTime)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(!output.contains("type NaiveDateTime"));
+    assert!(!output.contains("type OrderedMap"));
+    assert!(!output.contains("encodeNaiveDateTime"));
+    assert!(!output.contains("encodeOrderedMap"));
+}
+
+#[test]
+fn module_with_string_newtypes() {
+    #[derive(AsPursType)]
+    struct Release {
+        version: semver::Version,
+        homepage: Option<url::Url>,
+        mirrors: Vec<url::Url>,
+    }
+
+    let module = purs_module!("Releases".to_string() ; Release);
+    let output = format!("{}", &module);
+    assert!(output.contains(
+        "data Release = Release { version :: String, homepage :: Maybe String, mirrors :: Array String }"
+    ));
+    assert!(!output.contains("newtype"));
+
+    let module = purs_module!("Releases".to_string() ; Release)
+        .with_string_newtypes()
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Data.Newtype (\n  class Newtype\n)\n"));
+    assert!(output.contains(
+        "data Release = Release { version :: Version, homepage :: Maybe Url, mirrors :: Array Url }"
+    ));
+    assert!(output.ends_with(
+        "newtype Url = Url String
+
+derive instance newtypeUrl :: Newtype Url _
+derive newtype instance eqUrl :: Eq Url
+derive newtype instance ordUrl :: Ord Url
+derive newtype instance encodeJsonUrl :: EncodeJson Url
+derive newtype instance decodeJsonUrl :: DecodeJson Url
+
+newtype Version = Version String
+
+derive instance newtypeVersion :: Newtype Version _
+derive newtype instance eqVersion :: Eq Version
+derive newtype instance ordVersion :: Ord Version
+derive newtype instance encodeJsonVersion :: EncodeJson Version
+derive newtype instance decodeJsonVersion :: DecodeJson Version
+"
+    ));
+}
+
+#[test]
+fn module_with_opaque_types() {
+    /// A session token, only ever sent back to the server.
+    #[derive(AsPursType)]
+    #[purs(opaque)]
+    struct Token {
+        secret: String,
+    }
+
+    #[derive(AsPursType)]
+    #[purs(opaque, module = "Auth.Permissions")]
+    struct Permissions {
+        bits: i32,
+    }
+
+    #[derive(AsPursType)]
+    struct Session {
+        token: Token,
+        permissions: Permissions,
+    }
+
+    let module = purs_module!("Auth".to_string() ; Token, Permissions, Session).with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("import Auth.Permissions (\n  Permissions\n)\n"));
+    assert!(output.contains("import Unsafe.Coerce (\n  unsafeCoerce\n)\n"));
+    assert!(output.contains(
+        "-- | A session token, only ever sent back to the server.
+foreign import data Token :: Type
+
+instance encodeJsonToken :: EncodeJson Token where
+  encodeJson = unsafeCoerce
+
+instance decodeJsonToken :: DecodeJson Token where
+  decodeJson = Right <<< unsafeCoerce
+"
+    ));
+    assert!(!output.contains("genericToken"));
+    assert!(!output.contains("data Permissions"));
+    assert!(output.contains("data Session = Session { token :: Token, permissions :: Permissions }"));
+}
+
+#[test]
+fn module_with_reference_only_types() {
+    #[derive(AsPursConstructor)]
+    #[purs(module = "Shared.Users")]
+    struct UserId(u64);
+
+    #[derive(AsPursConstructor)]
+    #[purs(module = "Shared.Paging")]
+    struct Cursor {
+        offset: i32,
+    }
+
+    #[derive(AsPursType)]
+    struct Team {
+        owner: UserId,
+        members: Vec<UserId>,
+        next: Option<Cursor>,
+    }
+
+    assert_eq!(
+        UserId::as_purs_constructor(),
+        PursConstructor::new("UserId", Some("Shared.Users"), vec![])
+    );
+
+    let module = purs_module!("Teams".to_string() ; Team);
+    let output = format!("{}", &module);
+    assert!(output.contains("import Shared.Paging (\n  Cursor\n)\nimport Shared.Users (\n  UserId\n)\n"));
+    assert!(output.contains("data Team = Team { owner :: UserId, members :: Array UserId, next :: Maybe Cursor }"));
+}
+
+#[test]
+fn module_policies_apply_to_record_fields() {
+    use std::time::Duration;
+
+    #[derive(AsPursType)]
+    struct Job {
+        timeout: Option<Duration>,
+    }
+
+    let module = purs_module!("Jobs".to_string() ; Job).with_wide_integers(WideIntegers::BigInt);
+    let output = format!("{}", &module);
+    assert!(output.contains("import JS.BigInt (\n  BigInt\n)\n"));
+    assert!(output.contains("data Job = Job { timeout :: Maybe { secs :: BigInt, nanos :: Int } }"));
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate void;
+
+use void::Void;
+use purescript_waterslide::*;
+
+/// Stands for a crate whose types can't derive `AsPursType`.
+mod domain {
+    pub struct Money {
+        pub cents: i64,
+        pub currency: String,
+    }
+
+    pub struct Page<T> {
+        pub items: Vec<T>,
+        pub total: u64,
+    }
+
+    pub enum Visibility {
+        Public,
+        Private,
+    }
+}
+
+#[derive(AsPursType, Serialize)]
+#[serde(remote = "domain::Money")]
+struct MoneyDef {
+    cents: i64,
+    currency: String,
+}
+
+#[derive(AsPursType)]
+#[purs(remote = "domain::Page")]
+struct PageDef<T> {
+    items: Vec<T>,
+    total: u64,
+}
+
+#[derive(AsPursType, Serialize)]
+#[serde(remote = "domain::Visibility")]
+#[purs(name = "Audience")]
+enum VisibilityDef {
+    Public,
+    Private,
+}
+
+#[derive(AsPursType, Serialize)]
+struct Listing {
+    #[serde(with = "MoneyDef")]
+    price: domain::Money,
+    #[serde(with = "VisibilityDef")]
+    visibility: domain::Visibility,
+}
+
+#[test]
+fn remote_types_are_named_after_the_remote_type() {
+    assert_eq!(
+        &format!("{}", MoneyDef::as_purs_type()),
+        "data Money = Money { cents :: Number, currency :: String }"
+    );
+    assert_eq!(
+        &format!("{}", PageDef::<Void>::as_purs_type()),
+        "data Page t = Page { items :: Array t, total :: Number }"
+    );
+}
+
+#[test]
+fn remote_types_can_be_renamed() {
+    assert_eq!(
+        &format!("{}", VisibilityDef::as_purs_type()),
+        "data Audience = Public | Private"
+    );
+}
+
+#[test]
+fn fields_of_remote_types() {
+    assert_eq!(
+        &format!("{}", Listing::as_purs_type()),
+        "data Listing = Listing { price :: Money, visibility :: Audience }"
+    );
+
+    let listing = Listing {
+        price: domain::Money {
+            cents: 1250,
+            currency: "EUR".to_string(),
+        },
+        visibility: domain::Visibility::Public,
+    };
+    assert_eq!(
+        serde_json::to_string(&listing).unwrap(),
+        r#"{"price":{"cents":1250,"currency":"EUR"},"visibility":"Public"}"#
+    );
+}
+
+#[test]
+fn remote_types_in_modules() {
+    let module = purs_module!("Listings".to_string() ; MoneyDef, VisibilityDef, Listing);
+    let output = format!("{}", &module);
+    assert!(output.contains("data Money = Money { cents :: Number, currency :: String }"));
+    assert!(output.contains("data Audience = Public | Private"));
+    assert!(output.contains("derive instance genericAudience :: Generic Audience"));
+}
@@ -3,6 +3,12 @@
 #[macro_use]
 extern crate purescript_waterslide_derive;
 extern crate purescript_waterslide;
+extern crate chrono;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate time;
 extern crate void;
 
 use void::Void;
@@ -28,29 +34,20 @@ fn plain_old_struct() {
     assert_eq!(
         Plain::as_purs_type(),
         PursType::Struct(
-            PursConstructor {
-                module: None,
-                name: "Plain".to_string(),
-                parameters: vec![],
-            },
+            PursConstructor::new("Plain", None, vec![]),
             vec![
-                (
-                    "age".to_string(),
-                    PursConstructor {
-                        name: "Int".to_string(),
-                        module: Some("PRIM".to_string()),
-                        parameters: vec![],
-                    },
-                ),
-                (
-                    "name".to_string(),
-                    PursConstructor {
-                        name: "String".to_string(),
-                        module: Some("PRIM".to_string()),
-                        parameters: vec![],
-                    },
-                ),
-            ]
+                PursField {
+                    name: "age".to_string(),
+                    type_: PursConstructor::new("Int", Some("PRIM"), vec![]),
+                    doc: None,
+                },
+                PursField {
+                    name: "name".to_string(),
+                    type_: PursConstructor::new("String", Some("PRIM"), vec![]),
+                    doc: None,
+                },
+            ],
+            PursAttributes::default(),
         )
     );
 
@@ -158,7 +155,7 @@ fn unit_struct() {
 fn simple_generic_struct() {
     #[derive(AsPursType)]
     struct Paginated<T> {
-        page: u32,
+        page: i32,
         data: T,
     }
 
@@ -172,3 +169,189 @@ fn simple_generic_tuple_struct() {
 
     assert_derives_to!(Validated<Void>, "data Validated t = Validated t")
 }
+
+#[test]
+fn newtype_record() {
+    #[derive(AsPursType)]
+    #[purs(representation = "newtype")]
+    struct Credentials {
+        user: String,
+        password: String,
+    }
+
+    assert_derives_to!(
+        Credentials,
+        "newtype Credentials = Credentials { user :: String, password :: String }"
+    );
+}
+
+#[test]
+fn type_synonym_record() {
+    #[derive(AsPursType)]
+    #[purs(representation = "type")]
+    struct Paginated<T> {
+        page: i32,
+        data: T,
+    }
+
+    assert_derives_to!(Paginated<Void>, "type Paginated t = { page :: Int, data :: t }")
+}
+
+#[test]
+fn phantom_data_fields_are_units() {
+    use std::marker::PhantomData;
+
+    #[derive(AsPursType, Serialize, Deserialize)]
+    struct Id<T> {
+        value: i32,
+        kind: PhantomData<T>,
+    }
+
+    // serde writes `null` and requires the key when deserializing.
+    let id: Id<Void> = Id {
+        value: 1,
+        kind: PhantomData,
+    };
+    assert_eq!(serde_json::to_string(&id).unwrap(), r#"{"value":1,"kind":null}"#);
+    assert!(serde_json::from_str::<Id<Void>>(r#"{"value":1}"#).is_err());
+
+    assert_derives_to!(Id<Void>, "data Id t = Id { value :: Int, kind :: Unit }");
+}
+
+#[test]
+fn fields_skipped_by_serde_are_left_out() {
+    #[derive(AsPursType, Serialize)]
+    struct Account {
+        name: String,
+        #[serde(skip)]
+        cache: Vec<u8>,
+        #[serde(skip_serializing)]
+        password_hash: String,
+    }
+
+    #[derive(AsPursType, Serialize)]
+    struct Handle(i32, #[serde(skip)] bool);
+
+    #[derive(AsPursType, Serialize)]
+    enum Event {
+        Renamed(String, #[serde(skip)] u64),
+    }
+
+    assert_derives_to!(Account, "data Account = Account { name :: String }");
+    assert_derives_to!(Handle, "data Handle = Handle Int");
+    assert_derives_to!(Event, "data Event = Renamed String");
+}
+
+#[test]
+fn struct_with_std_types() {
+    use std::borrow::Cow;
+    use std::collections::VecDeque;
+    use std::net::IpAddr;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    #[derive(AsPursType)]
+    struct Connection<'a> {
+        peer: IpAddr,
+        name: Cow<'a, str>,
+        tags: Rc<[char]>,
+        queue: VecDeque<[u8; 4]>,
+        timeout: Option<Duration>,
+    }
+
+    assert_derives_to!(
+        Connection,
+        "data Connection = Connection { peer :: String, name :: String, tags :: Array Char, queue :: Array (Array Int), timeout :: Maybe { secs :: Number, nanos :: Int } }"
+    );
+}
+
+mod base64 {
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{} bytes", bytes.len()))
+    }
+}
+
+#[test]
+fn byte_buffers() {
+    #[derive(AsPursType, Serialize)]
+    struct Upload {
+        #[purs(bytes = "base64")]
+        content: Vec<u8>,
+        #[serde(serialize_with = "base64::serialize")]
+        signature: Vec<u8>,
+        #[purs(bytes = "array_buffer")]
+        thumbnail: Vec<u8>,
+        raw: Vec<u8>,
+        checksum: Base64Bytes,
+    }
+
+    assert_derives_to!(
+        Upload,
+        "data Upload = Upload { content :: String, signature :: String, thumbnail :: Uint8Array, raw :: Array Int, checksum :: String }"
+    );
+}
+
+#[test]
+fn timestamps() {
+    #[derive(AsPursType, Serialize)]
+    struct Session {
+        #[serde(with = "time::serde::rfc3339")]
+        started_at: time::OffsetDateTime,
+        #[serde(with = "time::serde::rfc3339::option")]
+        renewed_at: Option<time::OffsetDateTime>,
+        #[serde(with = "time::serde::timestamp")]
+        expires_at: time::OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::option")]
+        revoked_at: Option<time::OffsetDateTime>,
+        #[serde(with = "chrono::serde::ts_milliseconds")]
+        last_seen: chrono::DateTime<chrono::Utc>,
+    }
+
+    assert_derives_to!(
+        Session,
+        "data Session = Session { started_at :: String, renewed_at :: Maybe String, expires_at :: Number, revoked_at :: Maybe Number, last_seen :: Number }"
+    );
+}
+
+#[test]
+fn opaque_types() {
+    #[derive(AsPursType)]
+    #[purs(opaque)]
+    struct Token {
+        secret: String,
+    }
+
+    #[derive(AsPursType)]
+    #[purs(opaque)]
+    enum Handle<T> {
+        Open(T),
+        Closed,
+    }
+
+    assert_derives_to!(Token, "foreign import data Token :: Type");
+    assert_derives_to!(Handle<Void>, "foreign import data Handle :: Type -> Type");
+}
+
+#[test]
+fn type_variables_are_not_constructors() {
+    #[allow(non_camel_case_types)]
+    #[derive(AsPursType)]
+    struct t;
+
+    #[derive(AsPursType)]
+    struct Tagged<T> {
+        value: T,
+        tag: t,
+    }
+
+    let type_ = Tagged::<Void>::as_purs_type();
+    assert_eq!(type_.constructor().parameters(), vec![PursConstructor::variable("t")]);
+
+    let field_types = type_.field_types();
+    assert!(field_types[0].is_variable());
+    assert!(!field_types[1].is_variable());
+    assert_ne!(field_types[0], field_types[1]);
+    assert_eq!(&format!("{}", type_), "data Tagged t = Tagged { value :: t, tag :: t }");
+}
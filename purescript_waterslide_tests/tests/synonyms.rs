@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate purescript_waterslide_derive;
+extern crate purescript_waterslide;
+
+use std::collections::HashMap;
+use purescript_waterslide::*;
+
+#[derive(AsPursType)]
+struct UserId(u64);
+
+#[derive(AsPursType)]
+struct User {
+    name: String,
+}
+
+type UserMap = HashMap<UserId, User>;
+
+type Lookup<T> = HashMap<String, T>;
+
+#[derive(AsPursType)]
+struct Directory {
+    #[purs(synonym)]
+    users: UserMap,
+    #[purs(synonym)]
+    aliases: Lookup<UserId>,
+    admins: UserMap,
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(
+        &format!("{}", purs_alias!(UserMap)),
+        "type UserMap = Map UserId User"
+    );
+    assert_eq!(
+        &format!("{}", purs_alias!(Lookup<T>)),
+        "type Lookup t = Object t"
+    );
+}
+
+#[test]
+fn references_to_aliases() {
+    assert_eq!(
+        &format!("{}", Directory::as_purs_type()),
+        "data Directory = Directory { users :: UserMap, aliases :: Lookup UserId, admins :: Map UserId User }"
+    );
+}
+
+#[test]
+fn module_with_aliases() {
+    let module = purs_module!("Directory".to_string() ; UserId, User, Directory ; UserMap, Lookup<T>)
+        .with_codecs();
+    let output = format!("{}", &module);
+    assert!(output.contains("type UserMap = Map UserId User\n\ntype Lookup t = Object t\n"));
+    assert!(!output.contains("genericUserMap"));
+    assert!(!output.contains("encodeJsonUserMap"));
+    // Codecs follow the definitions of the synonyms.
+    assert!(output.contains("    [ Tuple \"users\" ((encodeMap encodeJson encodeJson) record.users)\n"));
+}
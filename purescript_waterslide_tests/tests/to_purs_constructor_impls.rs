@@ -1,14 +1,33 @@
 extern crate purescript_waterslide;
+extern crate arrayvec;
+extern crate bigdecimal;
 extern crate chrono;
+extern crate either;
+extern crate http;
+extern crate indexmap;
+extern crate ipnetwork;
+extern crate mime;
+extern crate nonempty;
+extern crate num_bigint;
+extern crate rust_decimal;
+extern crate semver;
+extern crate serde_json;
+extern crate smallvec;
+extern crate time;
+extern crate ulid;
+extern crate url;
 extern crate uuid;
+extern crate uuid1;
 
 use chrono::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use purescript_waterslide::AsPursConstructor;
 
 #[test]
 fn str_derives_as_expected() {
     assert_eq!(&format!("{}", <&str>::as_purs_constructor()), "String");
+    assert_eq!(<&str>::as_purs_constructor().module, Some("PRIM".to_string()));
 
 }
 
@@ -33,9 +52,121 @@ fn boxes_derive_as_expected() {
 
 }
 
+#[test]
+fn string_keyed_maps_derive_as_objects() {
+    assert_eq!(
+        &format!("{}", <HashMap<String, u8>>::as_purs_constructor()),
+        "Object Int"
+    );
+    assert_eq!(
+        &format!("{}", <BTreeMap<&str, Vec<u8>>>::as_purs_constructor()),
+        "Object (Array Int)"
+    );
+}
+
+#[test]
+fn other_maps_derive_as_data_maps() {
+    let map = <HashMap<i32, String>>::as_purs_constructor();
+    assert_eq!(&format!("{}", map), "Map Int String");
+    assert_eq!(map.module, Some("Data.Map".to_string()));
+    assert_eq!(
+        &format!("{}", <BTreeMap<(u8, u8), bool>>::as_purs_constructor()),
+        "Map (Tuple Int Int) Boolean"
+    );
+}
+
+#[test]
+fn sets_derive_as_expected() {
+    assert_eq!(
+        &format!("{}", <HashSet<String>>::as_purs_constructor()),
+        "Set String"
+    );
+    assert_eq!(
+        &format!("{}", <BTreeSet<u8>>::as_purs_constructor()),
+        "Set Int"
+    );
+}
+
+#[test]
+fn results_derive_as_either() {
+    let result = <Result<Vec<u8>, String>>::as_purs_constructor();
+    assert_eq!(&format!("{}", result), "Either String (Array Int)");
+    assert_eq!(result.module, Some("Data.Either".to_string()));
+}
+
+#[test]
+fn tuples_derive_as_nested_synonyms() {
+    assert_eq!(
+        &format!("{}", <(u8, String)>::as_purs_constructor()),
+        "Tuple Int String"
+    );
+    let triple = <(u8, String, bool)>::as_purs_constructor();
+    assert_eq!(&format!("{}", triple), "Tuple3 Int String Boolean");
+    assert_eq!(triple.module, Some("Data.Tuple.Nested".to_string()));
+
+    let large = <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::as_purs_constructor();
+    assert_eq!(large.name, "Tuple12");
+    assert_eq!(large.module, None);
+}
+
+#[test]
+fn unit_derives_as_expected() {
+    assert_eq!(&format!("{}", <()>::as_purs_constructor()), "Unit");
+}
+
+#[test]
+fn std_wrappers_derive_as_their_contents() {
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex};
+
+    assert_eq!(&format!("{}", <Arc<str>>::as_purs_constructor()), "String");
+    assert_eq!(
+        &format!("{}", <Mutex<RefCell<char>>>::as_purs_constructor()),
+        "Char"
+    );
+    assert_eq!(
+        &format!("{}", <::std::num::NonZeroU16>::as_purs_constructor()),
+        "Int"
+    );
+    assert_eq!(
+        &format!("{}", <::std::path::PathBuf>::as_purs_constructor()),
+        "String"
+    );
+}
+
+#[test]
+fn std_time_types_derive_as_records() {
+    assert_eq!(
+        &format!("{}", <::std::time::SystemTime>::as_purs_constructor()),
+        "{ secs_since_epoch :: Number, nanos_since_epoch :: Int }"
+    );
+}
+
+#[test]
+fn infallible_derives_as_void() {
+    let void = <::std::convert::Infallible>::as_purs_constructor();
+    assert_eq!(&format!("{}", void), "Void");
+    assert_eq!(void.module, Some("Data.Void".to_string()));
+}
+
+#[test]
+fn wide_integers_derive_as_numbers() {
+    assert_eq!(&format!("{}", <i64>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <u128>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <usize>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <u32>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <::std::num::NonZeroU32>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <::std::num::NonZeroU64>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <::std::num::NonZeroUsize>::as_purs_constructor()), "Number");
+    assert_eq!(&format!("{}", <u16>::as_purs_constructor()), "Int");
+    assert_eq!(<i64>::as_purs_constructor().module, Some("PRIM".to_string()));
+    assert_eq!(<i64>::as_purs_constructor(), <f64>::as_purs_constructor());
+}
+
 #[test]
 fn uuid_translates_as_expected() {
     assert_eq!(&format!("{}", uuid::Uuid::as_purs_constructor()), "String");
+    assert_eq!(uuid::Uuid::as_purs_constructor(), uuid1::Uuid::as_purs_constructor());
 }
 
 #[test]
@@ -49,6 +180,7 @@ fn chrono_datetime_translates_as_expected() {
 #[test]
 fn chrono_date_translates_as_expected() {
     assert_eq!(&format!("{}", Date::<Utc>::as_purs_constructor()), "String");
+    assert_eq!(Date::<Utc>::as_purs_constructor().module, Some("PRIM".to_string()));
 }
 
 #[test]
@@ -68,3 +200,115 @@ fn chrono_naive_date_translates_as_expected() {
 fn chrono_naive_time_translates_as_expected() {
     assert_eq!(&format!("{}", NaiveTime::as_purs_constructor()), "String");
 }
+
+#[test]
+fn serde_json_values_translate_as_expected() {
+    assert_eq!(&format!("{}", serde_json::Value::as_purs_constructor()), "Json");
+    assert_eq!(
+        serde_json::Value::as_purs_constructor().module,
+        Some("Data.Argonaut.Core".to_string())
+    );
+    assert_eq!(
+        &format!(
+            "{}",
+            <serde_json::Map<String, serde_json::Value>>::as_purs_constructor()
+        ),
+        "Json"
+    );
+    assert_eq!(
+        &format!("{}", <Box<serde_json::value::RawValue>>::as_purs_constructor()),
+        "Json"
+    );
+}
+
+#[test]
+fn time_types_translate_as_expected() {
+    assert_eq!(&format!("{}", time::OffsetDateTime::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", time::PrimitiveDateTime::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", time::Date::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", time::Time::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", time::Duration::as_purs_constructor()), "String");
+    assert_eq!(time::Duration::as_purs_constructor().module, Some("PRIM".to_string()));
+}
+
+#[test]
+fn big_numbers_translate_as_expected() {
+    let decimal = if cfg!(feature = "rust_decimal_float") { "Number" } else { "String" };
+    assert_eq!(&format!("{}", rust_decimal::Decimal::as_purs_constructor()), decimal);
+    assert_eq!(&format!("{}", bigdecimal::BigDecimal::as_purs_constructor()), "String");
+    assert_eq!(
+        &format!("{}", num_bigint::BigUint::as_purs_constructor()),
+        "Array Number"
+    );
+    assert_eq!(
+        &format!("{}", num_bigint::BigInt::as_purs_constructor()),
+        "Tuple Int (Array Number)"
+    );
+}
+
+#[test]
+fn identifier_and_network_types_translate_as_expected() {
+    assert_eq!(&format!("{}", url::Url::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", semver::Version::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", semver::VersionReq::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", ulid::Ulid::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", uuid1::Uuid::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", ipnetwork::IpNetwork::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", ipnetwork::Ipv4Network::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", ipnetwork::Ipv6Network::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", mime::Mime::as_purs_constructor()), "String");
+    assert_eq!(&format!("{}", http::StatusCode::as_purs_constructor()), "Int");
+    assert_eq!(&format!("{}", http::Method::as_purs_constructor()), "String");
+}
+
+#[test]
+fn collection_crates_translate_as_expected() {
+    assert_eq!(
+        &format!("{}", <indexmap::IndexMap<String, u8>>::as_purs_constructor()),
+        "OrderedMap String Int"
+    );
+    assert_eq!(
+        &format!("{}", <indexmap::IndexSet<String>>::as_purs_constructor()),
+        "Array String"
+    );
+    assert_eq!(
+        &format!("{}", <smallvec::SmallVec<[u8; 4]>>::as_purs_constructor()),
+        "Array Int"
+    );
+    assert_eq!(
+        &format!("{}", <arrayvec::ArrayVec<u8, 4>>::as_purs_constructor()),
+        "Array Int"
+    );
+    assert_eq!(
+        &format!("{}", <arrayvec::ArrayString<16>>::as_purs_constructor()),
+        "String"
+    );
+    assert_eq!(
+        &format!("{}", <either::Either<String, u8>>::as_purs_constructor()),
+        "Either String Int"
+    );
+    assert_eq!(
+        &format!("{}", <nonempty::NonEmpty<String>>::as_purs_constructor()),
+        "NonEmptyArray String"
+    );
+}
+
+#[test]
+fn records() {
+    use std::time::Duration;
+
+    let duration = Duration::as_purs_constructor();
+    assert!(duration.is_record());
+    assert!(!duration.is_application());
+    assert_eq!(
+        duration.record_fields(),
+        vec![
+            ("secs", &u64::as_purs_constructor()),
+            ("nanos", &i32::as_purs_constructor()),
+        ]
+    );
+    assert_eq!(
+        &format!("{}", <Vec<Duration>>::as_purs_constructor()),
+        "Array { secs :: Number, nanos :: Int }"
+    );
+}